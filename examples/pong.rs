@@ -2,7 +2,7 @@ use ruscii::app::{App, State};
 use ruscii::drawing::{Pencil, RectCharset};
 use ruscii::gui::FPSCounter;
 use ruscii::keyboard::{Key, KeyEvent};
-use ruscii::spatial::Vec2;
+use ruscii::spatial::{Rect, Vec2};
 use ruscii::terminal::{Color, Style, Window};
 
 use rand::{self, prelude::*};
@@ -135,7 +135,7 @@ fn main() {
             }
         }
 
-        fps_counter.update();
+        fps_counter.update_with(app_state);
         if app_state.step() % 2 == 0 {
             state.update();
         }
@@ -145,14 +145,15 @@ fn main() {
             state.left_player.score, state.right_player.score
         );
 
+        let board = Rect::from_dimension(win_size).centered(state.dimension);
+        let score = Rect::new(board.position - Vec2::y(1), Vec2::xy(board.dimension.x, 1))
+            .centered(Vec2::xy(score_msg.len(), 1));
+
         Pencil::new(window.canvas_mut())
             .draw_text(&format!("FPS: {}", fps_counter.count()), Vec2::xy(0, 0))
-            .set_origin(Vec2::xy(
-                (win_size.x - score_msg.len() as i32) / 2,
-                (win_size.y - state.dimension.y) / 2 - 1,
-            ))
+            .set_origin(score.position)
             .draw_text(score_msg, Vec2::xy(0, 0))
-            .set_origin((win_size - state.dimension) / 2)
+            .set_origin(board.position)
             .draw_rect(
                 &RectCharset::simple_round_lines(),
                 Vec2::zero(),
@@ -3,12 +3,17 @@ use ruscii::drawing::{Pencil, RectCharset};
 use ruscii::gui::FPSCounter;
 use ruscii::keyboard::{Key, KeyEvent};
 use ruscii::spatial::Vec2;
-use ruscii::terminal::{Color, Window};
+use ruscii::terminal::{Color, VisualElement, Window};
+use ruscii::tilemap::{Camera, TileMap};
+
+const FLOOR: usize = 0;
+const WALL: usize = 1;
 
 struct GameState {
     player_pos: Vec2,
     player_move: Vec2,
-    map_dim: Vec2,
+    map: TileMap,
+    camera: Camera,
 }
 
 impl GameState {
@@ -16,24 +21,54 @@ impl GameState {
         let future_pos = self.player_pos + self.player_move;
         self.player_move.clear();
 
-        if future_pos.x < (self.map_dim.x - 1)
-            && future_pos.x > 0
-            && future_pos.y < (self.map_dim.y - 1)
-            && future_pos.y > 0
-        {
+        if self.map.tile(future_pos) == Some(FLOOR) {
             self.player_pos = future_pos;
         }
+        self.camera.follow(self.player_pos);
+    }
+}
+
+fn build_map(dimension: Vec2) -> TileMap {
+    let mut map = TileMap::new(dimension, FLOOR);
+    for y in 0..dimension.y {
+        for x in 0..dimension.x {
+            let border = x == 0 || y == 0 || x == dimension.x - 1 || y == dimension.y - 1;
+            let pillar = x % 12 == 6 && y % 6 == 3;
+            if border || pillar {
+                map.set_tile(Vec2::xy(x, y), WALL);
+            }
+        }
     }
+    map.set_tile_kind(
+        FLOOR,
+        VisualElement {
+            value: '.',
+            foreground: Color::DarkGrey,
+            ..Default::default()
+        },
+    )
+    .set_tile_kind(
+        WALL,
+        VisualElement {
+            value: '#',
+            foreground: Color::Grey,
+            ..Default::default()
+        },
+    );
+    map
 }
 
 fn main() {
     let mut app = App::config(Config::new().fps(20));
     let size = app.window().size();
     let mut fps_counter = FPSCounter::default();
+    let map = build_map(size * 2);
+    let view_dim = size / 2;
     let mut state = GameState {
-        player_pos: size / 4,
-        player_move: Vec2::xy(1, 0),
-        map_dim: size / 2,
+        player_pos: map.dimension() / 2,
+        player_move: Vec2::zero(),
+        camera: Camera::new(view_dim - Vec2::xy(2, 2)).bounds(map.dimension()),
+        map,
     };
 
     app.run(|app_state: &mut State, window: &mut Window| {
@@ -47,10 +82,10 @@ fn main() {
 
         for key_down in app_state.keyboard().get_keys_down() {
             match key_down {
-                Key::H | Key::A => state.player_move = Vec2::x(-2),
+                Key::H | Key::A => state.player_move = Vec2::x(-1),
                 Key::J | Key::S => state.player_move = Vec2::y(1),
                 Key::K | Key::W => state.player_move = Vec2::y(-1),
-                Key::L | Key::D => state.player_move = Vec2::x(2),
+                Key::L | Key::D => state.player_move = Vec2::x(1),
                 _ => (),
             }
         }
@@ -63,8 +98,12 @@ fn main() {
             .draw_text("Press 'Q' or 'Esc' for exit", Vec2::y(2))
             .set_origin(size / 4)
             .set_foreground(Color::Grey)
-            .draw_rect(&RectCharset::double_lines(), Vec2::zero(), state.map_dim)
+            .draw_rect(&RectCharset::double_lines(), Vec2::zero(), view_dim)
+            .draw_tilemap(&state.map, &state.camera, Vec2::xy(1, 1))
             .set_foreground(Color::Yellow)
-            .draw_char('A', state.player_pos);
+            .draw_char(
+                'A',
+                state.camera.world_to_screen(state.player_pos) + Vec2::xy(1, 1),
+            );
     });
 }
@@ -2,11 +2,14 @@ use ruscii::app::{App, State};
 use ruscii::drawing::Pencil;
 use ruscii::gui::FPSCounter;
 use ruscii::keyboard::{Key, KeyEvent};
+use ruscii::particle::ParticleEmitter;
 use ruscii::spatial::Vec2;
 use ruscii::terminal::{Color, Style, Window};
 
 use rand::{self, prelude::*};
 
+use std::time::Duration;
+
 struct GameState {
     pub dimension: Vec2,
     pub spaceship: Vec2,
@@ -19,6 +22,7 @@ struct GameState {
     pub last_aliens_shots: usize,
     pub lives: usize,
     pub score: usize,
+    pub explosions: ParticleEmitter,
 }
 
 impl GameState {
@@ -43,6 +47,11 @@ impl GameState {
             last_aliens_shots: 0,
             lives: 3,
             score: 0,
+            explosions: ParticleEmitter::new(Vec2::zero())
+                .speed(2.0, 8.0)
+                .lifetime(Duration::from_millis(200), Duration::from_millis(600))
+                .glyphs(&['#', '*', '+', '.'])
+                .colors(&[Color::Yellow, Color::Red, Color::DarkGrey]),
         }
     }
 
@@ -64,6 +73,7 @@ impl GameState {
     pub fn update(&mut self, frame: usize) {
         let mut partial_score = 0;
         let aliens = &mut self.aliens;
+        let explosions = &mut self.explosions;
         self.spaceship_shots.retain(|shot| {
             if shot.y == 1 {
                 return false;
@@ -73,6 +83,7 @@ impl GameState {
             let destroyed = aliens.len() != pre_len;
             if destroyed {
                 partial_score += 5;
+                explosions.emit_at(*shot, 12);
             }
             !destroyed
         });
@@ -116,13 +127,9 @@ impl GameState {
             }
         });
 
-        self.lives = if damage >= self.lives {
-            0
-        } else {
-            self.lives - damage
-        };
+        self.lives = self.lives.saturating_sub(damage);
 
-        if self.aliens.len() > 0 {
+        if !self.aliens.is_empty() {
             let left = self.aliens.iter().min_by_key(|alien| alien.x).unwrap();
             let right = self.aliens.iter().max_by_key(|alien| alien.x).unwrap();
             if self.last_aliens_movement + 20 < frame {
@@ -132,9 +139,7 @@ impl GameState {
                     if self.aliens_movement.1 {
                         self.aliens_movement.0 = -self.aliens_movement.0;
                         let dir = self.aliens_movement.0;
-                        self.aliens
-                            .iter_mut()
-                            .for_each(|alien| alien.x = alien.x + dir);
+                        self.aliens.iter_mut().for_each(|alien| alien.x += dir);
                         self.aliens_movement.1 = false;
                     } else {
                         self.aliens.iter_mut().for_each(|alien| alien.y += 1);
@@ -142,9 +147,7 @@ impl GameState {
                     }
                 } else {
                     let dir = self.aliens_movement.0;
-                    self.aliens
-                        .iter_mut()
-                        .for_each(|alien| alien.x = alien.x + dir);
+                    self.aliens.iter_mut().for_each(|alien| alien.x += dir);
                 }
             }
         }
@@ -175,7 +178,8 @@ fn main() {
         }
 
         state.update(app_state.step());
-        fps_counter.update();
+        state.explosions.update(*app_state.dt());
+        fps_counter.update_with(app_state);
 
         let win_size = window.size();
         let mut pencil = Pencil::new(window.canvas_mut());
@@ -190,7 +194,7 @@ fn main() {
             let msg = &format!("{}  -  score: {}", status_msg, state.score);
             pencil.set_origin(win_size / 2 - Vec2::x(msg.len() / 2));
             pencil.draw_text(msg, Vec2::zero());
-            return ();
+            return;
         }
 
         pencil.set_origin((win_size - state.dimension) / 2);
@@ -214,6 +218,8 @@ fn main() {
             pencil.draw_char('W', *alien);
         }
 
+        pencil.draw_particles(&state.explosions);
+
         pencil.set_foreground(Color::Yellow);
         pencil.set_style(Style::Bold);
         for shot in &state.spaceship_shots {
@@ -7,7 +7,7 @@ use ruscii::terminal::Window;
 
 fn main() {
     let mut fps_counter = FPSCounter::default();
-    let mut app = App::config(Config::new().fps(std::u32::MAX));
+    let mut app = App::config(Config::new().fps(u32::MAX));
 
     app.run(|app_state: &mut State, window: &mut Window| {
         for key_event in app_state.keyboard().last_key_events() {
@@ -6,8 +6,10 @@ fn main() {
     println!("This is an open window");
     std::thread::sleep(std::time::Duration::from_secs(2));
 
-    let mut default = VisualElement::default();
-    default.background = Color::Red;
+    let default = VisualElement {
+        background: Color::Red,
+        ..Default::default()
+    };
     window.canvas_mut().set_default_element(&default);
     window.clear();
     window.draw();
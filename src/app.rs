@@ -10,14 +10,15 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::{panic, thread, time};
 
-/// Contains the [`App`] configuration settings, currently including only the framerate.
+/// Contains the [`App`] configuration settings: the framerate and the capture of the mouse.
 pub struct Config {
     pub fps: u32,
+    pub mouse: bool,
 }
 
 impl Config {
     pub fn new() -> Config {
-        Config { fps: 30 }
+        Config::default()
     }
 
     /// Consumes the receiver [`Config`] and returns a new one with the maximum framerate to the
@@ -26,12 +27,24 @@ impl Config {
         self.fps = fps;
         self
     }
+
+    /// Consumes the receiver [`Config`] and returns a new one that captures the mouse, so the
+    /// mouse wheel can be read with
+    /// [`Keyboard::last_wheel_delta`](crate::keyboard::Keyboard::last_wheel_delta), or not.
+    pub fn mouse(mut self, enable: bool) -> Config {
+        self.mouse = enable;
+        self
+    }
 }
 
 impl Default for Config {
-    /// Constructs a [`Config`] with a default maximum framerate of 30.
+    /// Constructs a [`Config`] with a default maximum framerate of 30 and the mouse not
+    /// captured.
     fn default() -> Self {
-        Self { fps: 30 }
+        Self {
+            fps: 30,
+            mouse: false,
+        }
     }
 }
 
@@ -42,6 +55,8 @@ pub struct State {
     running: Arc<AtomicBool>,
     keyboard: Keyboard,
     pub(self) dt: time::Duration,
+    pub(self) user_time: time::Duration,
+    pub(self) draw_time: time::Duration,
     pub(self) step: usize,
 }
 
@@ -64,10 +79,27 @@ impl State {
         &self.keyboard
     }
 
+    /// Returns the time elapsed between the start of the previous frame and the start of the
+    /// current one, including the time slept to limit the framerate. It is zero in the first
+    /// frame.
+    ///
+    /// This is the time to advance animations by. The time spent working on the previous frame
+    /// is split into [`State::user_time`] and [`State::draw_time`].
     pub fn dt(&self) -> &time::Duration {
         &self.dt
     }
 
+    /// Returns the time spent in the `frame_action` of the previous frame, including the clearing
+    /// of the window and the reading of the keyboard.
+    pub fn user_time(&self) -> &time::Duration {
+        &self.user_time
+    }
+
+    /// Returns the time spent in [`Window::draw`] in the previous frame.
+    pub fn draw_time(&self) -> &time::Duration {
+        &self.draw_time
+    }
+
     pub fn step(&self) -> usize {
         self.step
     }
@@ -114,21 +146,27 @@ impl App {
     {
         let expected_duration = time::Duration::from_nanos(1_000_000_000 / self.config.fps as u64);
         self.state.run();
+        self.window.set_mouse_capture(self.config.mouse);
 
         let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
             self.window.open();
+            let mut last_frame = time::Instant::now();
             while self.state.is_running() {
                 let now = time::Instant::now();
+                self.state.dt = now - last_frame;
+                last_frame = now;
                 self.window.clear();
 
                 self.state.keyboard.consume_key_events();
                 frame_action(&mut self.state, &mut self.window);
+                let drawing = time::Instant::now();
 
                 self.window.draw();
 
-                self.state.dt = now.elapsed();
+                self.state.user_time = drawing - now;
+                self.state.draw_time = drawing.elapsed();
                 self.state.step += 1;
-                if let Some(time) = expected_duration.checked_sub(self.state.dt) {
+                if let Some(time) = expected_duration.checked_sub(now.elapsed()) {
                     thread::sleep(time);
                 }
             }
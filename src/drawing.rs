@@ -8,6 +8,40 @@ use super::terminal::{Canvas, Color, Style};
 
 use num::cast::ToPrimitive;
 
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+mod animation;
+mod chart;
+mod figlet;
+mod gradient;
+mod junction;
+mod overlay;
+mod pattern;
+mod sprite;
+mod styled;
+mod table;
+mod text;
+mod theme;
+mod transform;
+
+pub use animation::{AnimationFrame, Animator, PlayMode};
+pub(crate) use chart::HORIZONTAL_EIGHTHS;
+pub use chart::{BarChart, Marker, Orientation, Plot, Series, SeriesKind};
+pub use figlet::{
+    FigletError, FigletFont, FigletLayout, SMUSH_BIG_X, SMUSH_EQUAL, SMUSH_HARDBLANK,
+    SMUSH_HIERARCHY, SMUSH_OPPOSITE_PAIR, SMUSH_UNDERSCORE,
+};
+pub use gradient::{Gradient, GradientDirection};
+pub use pattern::{BorderPattern, LinePattern};
+pub use sprite::{ColorMap, ColorMapError, Sprite, SpriteCell};
+pub use styled::{MarkupError, MarkupErrorKind, Span, StyledText};
+pub use table::{ColumnWidth, Table, TableCharset};
+pub use text::{HAlign, TextLayout, VAlign};
+pub use theme::{Theme, ThemeError};
+pub use transform::Rotation;
+pub(crate) use transform::{mirror_horizontal, mirror_vertical};
+
 /// The set of all characters needed to draw all edges and corners of a variable-length rectangle
 /// in the terminal.
 #[derive(Debug, Clone)]
@@ -67,33 +101,146 @@ impl RectCharset {
     pub fn double_lines() -> RectCharset {
         RectCharset::from("══║║╔╗╚╝")
     }
+
+    /// Returns a [`RectCharset`] for a heavy-line rectangle.
+    ///
+    /// # Rectangle
+    ///
+    /// Using this charset will provide a rectangle that looks like this:
+    ///
+    /// ```text
+    /// ┏━━━━━━┓
+    /// ┃ruscii┃
+    /// ┗━━━━━━┛
+    /// ```
+    pub fn heavy_lines() -> RectCharset {
+        RectCharset::from("━━┃┃┏┓┗┛")
+    }
+
+    /// Returns a [`RectCharset`] for a dashed single-line rectangle.
+    ///
+    /// # Rectangle
+    ///
+    /// Using this charset will provide a rectangle that looks like this:
+    ///
+    /// ```text
+    /// ┌┄┄┄┄┄┄┐
+    /// ┆ruscii┆
+    /// └┄┄┄┄┄┄┘
+    /// ```
+    pub fn dashed_lines() -> RectCharset {
+        RectCharset::from("┄┄┆┆┌┐└┘")
+    }
+
+    /// Returns a [`RectCharset`] for a dotted single-line rectangle.
+    ///
+    /// # Rectangle
+    ///
+    /// Using this charset will provide a rectangle that looks like this:
+    ///
+    /// ```text
+    /// ┌┈┈┈┈┈┈┐
+    /// ┊ruscii┊
+    /// └┈┈┈┈┈┈┘
+    /// ```
+    pub fn dotted_lines() -> RectCharset {
+        RectCharset::from("┈┈┊┊┌┐└┘")
+    }
+
+    /// Returns a [`RectCharset`] made of ASCII characters, for terminals without box-drawing
+    /// characters.
+    ///
+    /// # Rectangle
+    ///
+    /// Using this charset will provide a rectangle that looks like this:
+    ///
+    /// ```text
+    /// +------+
+    /// |ruscii|
+    /// +------+
+    /// ```
+    pub fn ascii() -> RectCharset {
+        RectCharset::from("--||++++")
+    }
+
+    /// Returns a [`RectCharset`] made of half blocks.
+    ///
+    /// # Rectangle
+    ///
+    /// Using this charset will provide a rectangle that looks like this:
+    ///
+    /// ```text
+    /// ▛▀▀▀▀▀▀▜
+    /// ▌ruscii▐
+    /// ▙▄▄▄▄▄▄▟
+    /// ```
+    pub fn block() -> RectCharset {
+        RectCharset::from("▀▄▌▐▛▜▙▟")
+    }
+
+    /// Parses a [`RectCharset`] from the first 8 characters of `item`, in the order top,
+    /// bottom, left, right, top-left, top-right, bottom-left and bottom-right.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::RectCharset;
+    /// #
+    /// assert_eq!(RectCharset::parse("━━┃┃┏┓┗┛").unwrap().top_left, '┏');
+    /// assert!(RectCharset::parse("━━┃┃").is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`RectCharsetError`] if `item` has fewer than 8 characters.
+    pub fn parse(item: &str) -> Result<RectCharset, RectCharsetError> {
+        let chars: Vec<char> = item.chars().collect();
+        match chars[..] {
+            [top, bottom, left, right, top_left, top_right, bottom_left, bottom_right, ..] => {
+                Ok(RectCharset {
+                    top,
+                    bottom,
+                    left,
+                    right,
+                    top_left,
+                    top_right,
+                    bottom_left,
+                    bottom_right,
+                })
+            }
+            _ => Err(RectCharsetError { found: chars.len() }),
+        }
+    }
 }
 
 impl From<&str> for RectCharset {
-    /// A utility function that generates a [`RectCharset`] from the characters in a `&str`.
+    /// A utility function that generates a [`RectCharset`] from the characters in a `&str`. See
+    /// [`RectCharset::parse`] for a version that does not panic.
     ///
     /// # Panics
     ///
     /// This function will `panic!` if the given `item` is a `&str` of fewer than 8 characters.
     fn from(item: &str) -> Self {
-        if item.len() < 8 {
-            panic!("Build a RectCharset requires at least 8 characters.");
-        }
+        RectCharset::parse(item).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
 
-        let mut chars = item.chars();
-        RectCharset {
-            top: chars.next().unwrap(),
-            bottom: chars.next().unwrap(),
-            left: chars.next().unwrap(),
-            right: chars.next().unwrap(),
-            top_left: chars.next().unwrap(),
-            top_right: chars.next().unwrap(),
-            bottom_left: chars.next().unwrap(),
-            bottom_right: chars.next().unwrap(),
-        }
+/// The error returned by [`RectCharset::parse`] when there are not enough characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RectCharsetError {
+    found: usize,
+}
+
+impl Display for RectCharsetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a RectCharset requires at least 8 characters, found {}",
+            self.found
+        )
     }
 }
 
+impl Error for RectCharsetError {}
+
 /// An object that stores several text style options and the [`Canvas`] to which text and shapes can
 /// be written.
 ///
@@ -106,6 +253,8 @@ impl From<&str> for RectCharset {
 /// - Foreground (character) [`Color`]
 /// - Background [`Color`]
 /// - [`Style`] (boldness)
+/// - Line merging - Whether box-drawing characters are joined with the ones already on the
+///   [`Canvas`]. See [`Pencil::set_line_merging`].
 ///
 /// # Examples
 ///
@@ -154,17 +303,21 @@ pub struct Pencil<'a> {
     foreground: Color,
     background: Color,
     style: Style,
+    line_merging: bool,
+    theme: Theme,
     canvas: &'a mut Canvas,
 }
 
 impl<'a> Pencil<'a> {
     /// Constructs a [`Pencil`] that can write to the given [`Canvas`].
-    pub fn new(canvas: &'a mut Canvas) -> Pencil {
+    pub fn new(canvas: &'a mut Canvas) -> Pencil<'a> {
         Pencil {
             origin: Vec2::zero(),
             foreground: canvas.default_element().foreground,
             background: canvas.default_element().background,
             style: canvas.default_element().style,
+            line_merging: false,
+            theme: Theme::default(),
             canvas,
         }
     }
@@ -172,7 +325,11 @@ impl<'a> Pencil<'a> {
     /// Sets a [`VisualElement`] cell of the [`Canvas`].
     fn draw_element(&mut self, position: Vec2, value: char) {
         if let Some(element) = self.canvas.elem_mut(position) {
-            element.value = value;
+            element.value = if self.line_merging {
+                junction::merge(element.value, value)
+            } else {
+                value
+            };
             element.foreground = self.foreground;
             element.background = self.background;
             element.style = self.style;
@@ -224,6 +381,15 @@ impl<'a> Pencil<'a> {
         self
     }
 
+    /// Shows the terminal cursor at the given `position` once the [`Canvas`] is drawn, for
+    /// example at the edit position of a text input. See [`Canvas::set_cursor`].
+    ///
+    /// Returns the receiver for chaining.
+    pub fn set_cursor(&mut self, position: Vec2) -> &mut Pencil<'a> {
+        self.canvas.set_cursor(Some(self.origin + position));
+        self
+    }
+
     pub fn set_foreground(&mut self, color: Color) -> &mut Pencil<'a> {
         self.foreground = color;
         self
@@ -239,6 +405,38 @@ impl<'a> Pencil<'a> {
         self
     }
 
+    pub fn line_merging(&self) -> bool {
+        self.line_merging
+    }
+
+    /// Enables or disables the merging of box-drawing characters.
+    ///
+    /// When enabled, a light, heavy, double or rounded box-drawing character drawn over another
+    /// one is joined with it, so that crossing or touching lines and rectangles produce the right
+    /// junctions (`┬`, `┼`, `╠`, ...) instead of broken corners. Any other character is drawn as
+    /// usual.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::{Pencil, RectCharset};
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(7, 3), &VisualElement::default());
+    /// Pencil::new(&mut canvas)
+    ///     .set_line_merging(true)
+    ///     .draw_rect(&RectCharset::simple_lines(), Vec2::zero(), Vec2::xy(4, 3))
+    ///     .draw_rect(&RectCharset::simple_lines(), Vec2::x(3), Vec2::xy(4, 3));
+    ///
+    /// assert_eq!(canvas.elem(Vec2::x(3)).unwrap().value, '┬');
+    /// assert_eq!(canvas.elem(Vec2::xy(3, 2)).unwrap().value, '┴');
+    /// ```
+    pub fn set_line_merging(&mut self, enable: bool) -> &mut Pencil<'a> {
+        self.line_merging = enable;
+        self
+    }
+
     /// Draws a character at the given `position` according to the previously set text style options.
     ///
     /// Returns the receiver for chaining.
@@ -250,7 +448,8 @@ impl<'a> Pencil<'a> {
     /// Draws a string at the given `position` according to the previously set text style options.
     ///
     /// If the string has multiple characters, each subsequent character is drawn one point to the
-    /// right (as you'd probably expect).
+    /// right (as you'd probably expect). To draw text with line breaks, word wrapping or
+    /// alignment inside a rectangle, see [`Pencil::draw_text_box`].
     ///
     /// Returns the receiver for chaining.
     pub fn draw_text(&mut self, text: &str, position: Vec2) -> &mut Pencil<'a> {
@@ -268,7 +467,7 @@ impl<'a> Pencil<'a> {
     ///
     /// Returns the receiver for chaining.
     pub fn draw_center_text(&mut self, text: &str, position: Vec2) -> &mut Pencil<'a> {
-        let position = position - Vec2::x(text.len() as i32 / 2);
+        let position = position - Vec2::x(text.chars().count() / 2);
         self.draw_text(text, position)
     }
 
@@ -276,7 +475,7 @@ impl<'a> Pencil<'a> {
     ///
     /// Returns the receiver for chaining.
     pub fn draw_right_aligned_text(&mut self, text: &str, position: Vec2) -> &mut Pencil<'a> {
-        let position = position - Vec2::x(text.len() as i32);
+        let position = position - Vec2::x(text.chars().count());
         self.draw_text(text, position)
     }
 
@@ -327,15 +526,12 @@ impl<'a> Pencil<'a> {
         position: Vec2,
         dimension: Vec2,
     ) -> &mut Pencil<'a> {
+        let inner = Vec2::xy((dimension.x - 2).max(0), (dimension.y - 2).max(0));
         self.move_origin(position)
-            .draw_hline(charset.top, Vec2::x(0), dimension.x - 1)
-            .draw_hline(
-                charset.bottom,
-                Vec2::xy(0, dimension.y - 1),
-                dimension.x - 1,
-            )
-            .draw_vline(charset.left, Vec2::y(0), dimension.y - 1)
-            .draw_vline(charset.right, Vec2::xy(dimension.x - 1, 0), dimension.y - 1)
+            .draw_hline(charset.top, Vec2::x(1), inner.x)
+            .draw_hline(charset.bottom, Vec2::xy(1, dimension.y - 1), inner.x)
+            .draw_vline(charset.left, Vec2::y(1), inner.y)
+            .draw_vline(charset.right, Vec2::xy(dimension.x - 1, 1), inner.y)
             .draw_char(charset.top_left, Vec2::xy(0, 0))
             .draw_char(charset.top_right, Vec2::x(dimension.x - 1))
             .draw_char(charset.bottom_left, Vec2::y(dimension.y - 1))
@@ -347,6 +543,19 @@ impl<'a> Pencil<'a> {
     /// `position` sets the position of the top-left corner of the rectangle. The rectangle is
     /// composed of the given `fill` characters.
     ///
+    /// ```rust
+    /// # use ruscii::drawing::Pencil;
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(6, 4), &VisualElement::default());
+    /// Pencil::new(&mut canvas).draw_filled_rect('#', Vec2::xy(1, 1), Vec2::xy(3, 2));
+    ///
+    /// assert_eq!(canvas.elem(Vec2::xy(1, 1)).unwrap().value, '#');
+    /// assert_eq!(canvas.elem(Vec2::xy(3, 2)).unwrap().value, '#');
+    /// assert_eq!(canvas.elem(Vec2::xy(4, 3)).unwrap().value, ' ');
+    /// ```
+    ///
     /// Returns the receiver for chaining.
     pub fn draw_filled_rect(
         &mut self,
@@ -356,96 +565,8 @@ impl<'a> Pencil<'a> {
     ) -> &mut Pencil<'a> {
         self.move_origin(position);
         for i in 0..dimension.x {
-            self.draw_vline(fill, Vec2::xy(position.x + i, position.y), dimension.y);
+            self.draw_vline(fill, Vec2::x(i), dimension.y);
         }
         self.move_origin(-position)
     }
-
-    /// Draws one of the frames of the given `animator` based on the number of times this has
-    /// been previously called.
-    pub fn draw_animator(&mut self, animator: &mut Animator, position: Vec2) {
-        let frame = &animator.access_frame();
-        self.draw_text(&frame.text, position + frame.delta);
-    }
-}
-
-/// An object that runs an animation.
-///
-/// ## Example
-///
-/// An [`Animator`] must be supplied with a [`Vec`] of [`AnimationFrame`]s and then drawn by a
-/// [`Pencil`] like so:
-///
-/// ```rust
-/// # use ruscii::drawing::{AnimationFrame, Animator, Pencil};
-/// # use ruscii::spatial::Vec2;
-/// # use ruscii::terminal::{Canvas, VisualElement};
-/// # let mut canvas = Canvas::new(Vec2::xy(3, 1), &VisualElement::default());
-/// # let mut pencil = Pencil::new(&mut canvas);
-/// let animation = vec![
-///     AnimationFrame::new("X", Vec2::zero(), 1),
-///     AnimationFrame::new("X", Vec2::x(1), 1),
-///     AnimationFrame::new("X", Vec2::x(2), 1),
-///     AnimationFrame::new("X", Vec2::x(1), 1),
-/// ];
-/// let mut animator = Animator::new(animation);
-///
-/// pencil.draw_animator(&mut animator, Vec2::zero());
-/// ```
-pub struct Animator {
-    animation: Vec<AnimationFrame>,
-    frame_index: usize,
-    counter: u32,
-    speed: u32,
-}
-
-impl Animator {
-    /// Creates a new [`Animator`] with the given `animation`.
-    pub fn new(animation: Vec<AnimationFrame>) -> Animator {
-        Animator {
-            animation,
-            frame_index: 0,
-            counter: 0,
-            speed: 1,
-        }
-    }
-
-    /// Sets the speed of animation.
-    pub fn set_speed(&mut self, speed: u32) {
-        self.speed = speed
-    }
-
-    /// Gets the text of the current frame and updates the frame counter, changing the current
-    /// frame if the duration is exceeded.
-    fn access_frame(&mut self) -> AnimationFrame {
-        let current_frame = &self.animation[self.frame_index];
-
-        self.counter += 1;
-        if self.counter >= current_frame.duration / self.speed {
-            self.counter = 0;
-            self.frame_index = (self.frame_index + 1) % self.animation.len();
-        }
-
-        current_frame.to_owned()
-    }
-}
-
-/// A single frame of an animation.
-#[derive(Clone, Debug, PartialEq)]
-pub struct AnimationFrame {
-    text: String,
-    delta: Vec2,
-    duration: u32,
-}
-
-impl AnimationFrame {
-    /// Creates an [`AnimationFrame`] from the given `text` that lasts for the given `duration` and
-    /// is displaced by the given `delta`.
-    pub fn new(text: impl Into<String>, delta: Vec2, duration: u32) -> AnimationFrame {
-        AnimationFrame {
-            text: text.into(),
-            delta,
-            duration,
-        }
-    }
 }
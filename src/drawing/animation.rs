@@ -0,0 +1,351 @@
+//! Time-based animations made of frames.
+
+use super::{Pencil, Sprite};
+use crate::spatial::Vec2;
+
+use std::time::Duration;
+
+/// The order in which an [`Animator`] plays its frames.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PlayMode {
+    /// From the first frame to the last one, starting over again.
+    Loop,
+    /// From the first frame to the last one, stopping at the last one.
+    Once,
+    /// From the first frame to the last one and back, starting over again.
+    PingPong,
+    /// From the last frame to the first one, starting over again.
+    Reverse,
+}
+
+/// An object that runs an animation.
+///
+/// The animation advances by the time passed to [`Animator::update`], usually the
+/// [`State::dt`](crate::app::State::dt) of each frame, so it runs at the same pace regardless of
+/// the framerate.
+///
+/// ## Example
+///
+/// An [`Animator`] must be supplied with a [`Vec`] of [`AnimationFrame`]s, updated with the
+/// elapsed time and then drawn by a [`Pencil`] like so:
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use ruscii::drawing::{AnimationFrame, Animator, Pencil, PlayMode};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// # let mut canvas = Canvas::new(Vec2::xy(3, 1), &VisualElement::default());
+/// # let mut pencil = Pencil::new(&mut canvas);
+/// let step = Duration::from_millis(100);
+/// let animation = vec![
+///     AnimationFrame::new("X", Vec2::zero(), step),
+///     AnimationFrame::new("X", Vec2::x(1), step),
+///     AnimationFrame::new("X", Vec2::x(2), step),
+/// ];
+/// let mut animator = Animator::new(animation);
+/// animator.set_mode(PlayMode::Once);
+///
+/// animator.update(Duration::from_millis(150));
+/// assert_eq!(animator.frame_index(), 1);
+/// pencil.draw_animator(&animator, Vec2::zero());
+///
+/// animator.update(Duration::from_secs(1));
+/// assert_eq!(animator.frame_index(), 2);
+/// assert!(animator.is_finished());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Animator {
+    animation: Vec<AnimationFrame>,
+    mode: PlayMode,
+    speed: f32,
+    paused: bool,
+    frame_index: usize,
+    frame_elapsed: Duration,
+    forward: bool,
+    finished: bool,
+    loops: usize,
+}
+
+impl Animator {
+    /// Creates a new [`Animator`] with the given `animation`, playing in [`PlayMode::Loop`] at
+    /// normal speed.
+    pub fn new(animation: Vec<AnimationFrame>) -> Animator {
+        Animator {
+            animation,
+            mode: PlayMode::Loop,
+            speed: 1.0,
+            paused: false,
+            frame_index: 0,
+            frame_elapsed: Duration::ZERO,
+            forward: true,
+            finished: false,
+            loops: 0,
+        }
+    }
+
+    /// Sets the speed of animation, as a factor of the frame durations. A `speed` of `2.0` plays
+    /// the animation twice as fast. Negative and NaN values are taken as zero, and infinite ones
+    /// as [`f32::MAX`].
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::Animator;
+    /// #
+    /// let mut animator = Animator::new(Vec::new());
+    /// assert_eq!(animator.set_speed(-2.0).speed(), 0.0);
+    /// assert_eq!(animator.set_speed(f32::NAN).speed(), 0.0);
+    /// assert_eq!(animator.set_speed(f32::INFINITY).speed(), f32::MAX);
+    /// ```
+    pub fn set_speed(&mut self, speed: f32) -> &mut Animator {
+        self.speed = match speed > 0.0 {
+            true => speed.min(f32::MAX),
+            false => 0.0,
+        };
+        self
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the [`PlayMode`] and restarts the animation.
+    pub fn set_mode(&mut self, mode: PlayMode) -> &mut Animator {
+        self.mode = mode;
+        self.reset()
+    }
+
+    pub fn mode(&self) -> PlayMode {
+        self.mode
+    }
+
+    pub fn pause(&mut self) -> &mut Animator {
+        self.paused = true;
+        self
+    }
+
+    pub fn resume(&mut self) -> &mut Animator {
+        self.paused = false;
+        self
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns `true` once an animation in [`PlayMode::Once`] has reached the end of its last
+    /// frame. Animations in other modes never finish.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the number of times the animation has started over since the last reset.
+    pub fn loops(&self) -> usize {
+        self.loops
+    }
+
+    pub fn frame_index(&self) -> usize {
+        self.frame_index
+    }
+
+    /// Returns the frame that should be drawn now, or [`None`] if the animation has no frames.
+    pub fn current_frame(&self) -> Option<&AnimationFrame> {
+        self.animation.get(self.frame_index)
+    }
+
+    /// Returns the time that a whole cycle of the animation takes at normal speed.
+    pub fn duration(&self) -> Duration {
+        self.animation.iter().map(|frame| frame.duration).sum()
+    }
+
+    /// Moves the animation back to its first frame, clearing the finished state and the loop
+    /// count. The paused state is kept.
+    pub fn reset(&mut self) -> &mut Animator {
+        self.frame_index = match self.mode {
+            PlayMode::Reverse => self.animation.len().saturating_sub(1),
+            _ => 0,
+        };
+        self.frame_elapsed = Duration::ZERO;
+        self.forward = true;
+        self.finished = false;
+        self.loops = 0;
+        self
+    }
+
+    /// Moves the animation to the given `time` from its start, regardless of its speed.
+    pub fn seek(&mut self, time: Duration) -> &mut Animator {
+        self.reset();
+        self.advance(time);
+        self
+    }
+
+    /// Moves the animation to the start of the frame with the given `index`.
+    pub fn seek_frame(&mut self, index: usize) -> &mut Animator {
+        self.reset();
+        if index < self.animation.len() {
+            self.frame_index = index;
+        }
+        self
+    }
+
+    /// Advances the animation by the elapsed time `dt`, scaled by its speed. Paused and finished
+    /// animations do not advance.
+    ///
+    /// Whole cycles of looping animations are skipped at once, so a large `dt`, after a pause or
+    /// at a high speed, takes no longer than a short one:
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use ruscii::drawing::{AnimationFrame, Animator};
+    /// # use ruscii::spatial::Vec2;
+    /// #
+    /// let step = Duration::from_millis(100);
+    /// let frames = (0..3).map(|_| AnimationFrame::new("X", Vec2::zero(), step)).collect();
+    /// let mut animator = Animator::new(frames);
+    ///
+    /// animator.update(Duration::from_secs(1_000_000) + Duration::from_millis(150));
+    /// assert_eq!(animator.frame_index(), 2);
+    /// assert_eq!(animator.loops(), 3_333_333);
+    /// ```
+    pub fn update(&mut self, dt: Duration) -> &mut Animator {
+        if !self.paused {
+            let time = Duration::try_from_secs_f64(dt.as_secs_f64() * self.speed as f64);
+            self.advance(time.unwrap_or(Duration::MAX));
+        }
+        self
+    }
+
+    /// Returns the time after which a looping animation is back at the same frame, or [`None`]
+    /// for [`PlayMode::Once`].
+    fn cycle(&self) -> Option<Duration> {
+        match self.mode {
+            PlayMode::Once => None,
+            PlayMode::Loop | PlayMode::Reverse => Some(self.duration()),
+            PlayMode::PingPong => match &self.animation[..] {
+                [] => None,
+                [frame] => Some(frame.duration * 2),
+                [first, .., last] => Some(self.duration() * 2 - first.duration - last.duration),
+            },
+        }
+    }
+
+    fn advance(&mut self, time: Duration) {
+        if self.animation.is_empty() || self.finished {
+            return;
+        }
+        if self.mode != PlayMode::Once && self.duration().is_zero() {
+            return;
+        }
+
+        let mut remaining = self.frame_elapsed.saturating_add(time);
+        if let Some(cycle) = self.cycle().filter(|cycle| !cycle.is_zero()) {
+            let cycles = remaining.as_nanos() / cycle.as_nanos();
+            self.loops = self
+                .loops
+                .saturating_add(cycles.min(usize::MAX as u128) as usize);
+            remaining = Duration::from_nanos((remaining.as_nanos() % cycle.as_nanos()) as u64);
+        }
+        loop {
+            let duration = self.animation[self.frame_index].duration;
+            if remaining < duration {
+                self.frame_elapsed = remaining;
+                return;
+            }
+            remaining -= duration;
+            self.next_frame();
+            if self.finished {
+                self.frame_elapsed = duration;
+                return;
+            }
+        }
+    }
+
+    fn next_frame(&mut self) {
+        let last = self.animation.len() - 1;
+        match self.mode {
+            PlayMode::Loop => {
+                if self.frame_index == last {
+                    self.frame_index = 0;
+                    self.loops += 1;
+                } else {
+                    self.frame_index += 1;
+                }
+            }
+            PlayMode::Reverse => {
+                if self.frame_index == 0 {
+                    self.frame_index = last;
+                    self.loops += 1;
+                } else {
+                    self.frame_index -= 1;
+                }
+            }
+            PlayMode::Once => {
+                if self.frame_index == last {
+                    self.finished = true;
+                } else {
+                    self.frame_index += 1;
+                }
+            }
+            PlayMode::PingPong => {
+                if self.forward && self.frame_index == last {
+                    self.forward = false;
+                } else if !self.forward && self.frame_index == 0 {
+                    self.forward = true;
+                    self.loops += 1;
+                }
+                self.frame_index = match self.forward {
+                    true => (self.frame_index + 1).min(last),
+                    false => self.frame_index.saturating_sub(1),
+                };
+            }
+        }
+    }
+}
+
+/// A single frame of an animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationFrame {
+    sprite: Sprite,
+    delta: Vec2,
+    duration: Duration,
+}
+
+impl AnimationFrame {
+    /// Creates an [`AnimationFrame`] from the given `text` that lasts for the given `duration` and
+    /// is displaced by the given `delta`. The text can have several lines.
+    pub fn new(text: &str, delta: Vec2, duration: Duration) -> AnimationFrame {
+        AnimationFrame::from_sprite(Sprite::from_text(text, None), delta, duration)
+    }
+
+    /// Creates an [`AnimationFrame`] from the given `sprite`, allowing colored frames with
+    /// transparent cells.
+    pub fn from_sprite(sprite: Sprite, delta: Vec2, duration: Duration) -> AnimationFrame {
+        AnimationFrame {
+            sprite,
+            delta,
+            duration,
+        }
+    }
+
+    pub fn sprite(&self) -> &Sprite {
+        &self.sprite
+    }
+
+    pub fn delta(&self) -> Vec2 {
+        self.delta
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws the current frame of the given `animator`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_animator(&mut self, animator: &Animator, position: Vec2) -> &mut Pencil<'a> {
+        if let Some(frame) = animator.current_frame() {
+            self.draw_sprite(&frame.sprite, position + frame.delta);
+        }
+        self
+    }
+}
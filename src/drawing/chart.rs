@@ -0,0 +1,631 @@
+//! Bar charts, sparklines and plots of numeric data.
+
+use super::Pencil;
+use crate::spatial::Vec2;
+use crate::terminal::Color;
+
+/// Blocks that fill a cell from the bottom, by eighths.
+pub(crate) const VERTICAL_EIGHTHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Blocks that fill a cell from the left, by eighths.
+pub(crate) const HORIZONTAL_EIGHTHS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// The direction in which a chart or a bar grows.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Orientation {
+    /// From the bottom to the top.
+    Vertical,
+    /// From the left to the right.
+    Horizontal,
+}
+
+/// A chart with a bar for each value, drawn with [`Pencil::draw_bar_chart`].
+///
+/// Bars are drawn with eighth-block glyphs, so their length has a resolution of an eighth of a
+/// cell. Negative and non-finite values, such as missing samples set to NaN, are drawn as empty
+/// bars.
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{BarChart, Orientation, Pencil};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let chart = BarChart::new(&[2.0, 1.0, 0.5])
+///     .orientation(Orientation::Horizontal)
+///     .labels(&["a", "b", "c"])
+///     .gap(0);
+///
+/// let mut canvas = Canvas::new(Vec2::xy(6, 3), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_bar_chart(&chart, Vec2::zero(), Vec2::xy(6, 3));
+/// let row = |y| (0..6).map(|x| canvas.elem(Vec2::xy(x, y)).unwrap().value).collect::<String>();
+/// assert_eq!(row(0), "a ████");
+/// assert_eq!(row(1), "b ██  ");
+/// assert_eq!(row(2), "c █   ");
+///
+/// let chart = BarChart::new(&[2.0, f64::NAN, 1.0])
+///     .orientation(Orientation::Horizontal)
+///     .gap(0);
+/// let mut canvas = Canvas::new(Vec2::xy(4, 3), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_bar_chart(&chart, Vec2::zero(), Vec2::xy(4, 3));
+/// assert_eq!(canvas.elem(Vec2::xy(0, 0)).unwrap().value, '█');
+/// assert_eq!(canvas.elem(Vec2::xy(0, 1)).unwrap().value, ' ');
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarChart {
+    values: Vec<f64>,
+    labels: Vec<String>,
+    colors: Vec<Color>,
+    orientation: Orientation,
+    max: Option<f64>,
+    bar_width: usize,
+    gap: usize,
+}
+
+impl BarChart {
+    /// Creates a vertical [`BarChart`] with the given `values`, with bars one cell wide and
+    /// separated by one cell.
+    pub fn new(values: &[f64]) -> BarChart {
+        BarChart {
+            values: values.to_vec(),
+            labels: Vec::new(),
+            colors: Vec::new(),
+            orientation: Orientation::Vertical,
+            max: None,
+            bar_width: 1,
+            gap: 1,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one with the given [`Orientation`].
+    pub fn orientation(mut self, orientation: Orientation) -> BarChart {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with a label for each bar. Vertical charts
+    /// draw the labels below the bars and horizontal charts draw them on their left.
+    pub fn labels(mut self, labels: &[&str]) -> BarChart {
+        self.labels = labels.iter().map(|label| label.to_string()).collect();
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose bars take the given `colors` in turn.
+    /// Without colors, bars take the color of the [`Pencil`].
+    pub fn colors(mut self, colors: &[Color]) -> BarChart {
+        self.colors = colors.to_vec();
+        self
+    }
+
+    /// Consumes the receiver and returns a new one where a bar with the value `max` fills the
+    /// whole chart. By default it is the largest value.
+    pub fn max(mut self, max: f64) -> BarChart {
+        self.max = Some(max);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose bars are `width` cells thick.
+    pub fn bar_width(mut self, width: usize) -> BarChart {
+        self.bar_width = width.max(1);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with `gap` empty cells between its bars.
+    pub fn gap(mut self, gap: usize) -> BarChart {
+        self.gap = gap;
+        self
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    fn colors_at(&self, index: usize) -> Option<Color> {
+        match self.colors.len() {
+            0 => None,
+            len => Some(self.colors[index % len]),
+        }
+    }
+
+    /// Returns the number of eighths of a cell of a bar with the given `value` in a chart whose
+    /// bars are at most `cells` long.
+    fn eighths(&self, value: f64, cells: i32) -> i32 {
+        let max = self.max.unwrap_or_else(|| {
+            self.values
+                .iter()
+                .cloned()
+                .filter(|value| value.is_finite())
+                .fold(0.0, f64::max)
+        });
+        if !value.is_finite() || value <= 0.0 || !max.is_finite() || max <= 0.0 {
+            return 0;
+        }
+        ((value / max).min(1.0) * (cells.max(0) * 8) as f64).round() as i32
+    }
+}
+
+/// The way a [`Series`] is drawn.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SeriesKind {
+    /// The points joined by straight lines.
+    Line,
+    /// The points alone.
+    Scatter,
+}
+
+/// The glyph used to draw the points and lines of a [`Series`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Marker {
+    /// Braille dots, with a resolution of 2×4 dots per cell.
+    Braille,
+    /// The given character, with a resolution of one point per cell.
+    Char(char),
+}
+
+/// A set of points of a [`Plot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    points: Vec<(f64, f64)>,
+    kind: SeriesKind,
+    marker: Marker,
+    color: Option<Color>,
+}
+
+impl Series {
+    /// Creates a [`Series`] of lines drawn with [`Marker::Braille`] from the given `(x, y)`
+    /// points.
+    pub fn new(points: &[(f64, f64)]) -> Series {
+        Series {
+            points: points.to_vec(),
+            kind: SeriesKind::Line,
+            marker: Marker::Braille,
+            color: None,
+        }
+    }
+
+    /// Creates a [`Series`] from the given `values`, using their index as `x`.
+    pub fn from_values(values: &[f64]) -> Series {
+        let points: Vec<(f64, f64)> = values
+            .iter()
+            .enumerate()
+            .map(|(x, &y)| (x as f64, y))
+            .collect();
+        Series::new(&points)
+    }
+
+    /// Consumes the receiver and returns a new one with the given [`SeriesKind`].
+    pub fn kind(mut self, kind: SeriesKind) -> Series {
+        self.kind = kind;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with the given [`Marker`].
+    pub fn marker(mut self, marker: Marker) -> Series {
+        self.marker = marker;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one drawn with the given `color`. Without a
+    /// color, the series takes the color of the [`Pencil`].
+    pub fn color(mut self, color: Color) -> Series {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+}
+
+/// A chart of one or more [`Series`] with axes, drawn with [`Pencil::draw_plot`].
+///
+/// The axes are labeled with the bounds of their ranges, which are computed from the points
+/// unless they are given.
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{Marker, Pencil, Plot, Series, SeriesKind};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, Color, VisualElement};
+/// #
+/// let plot = Plot::new()
+///     .series(Series::new(&[(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 9.0)]).color(Color::Green))
+///     .series(
+///         Series::from_values(&[9.0, 4.0, 1.0, 0.0])
+///             .kind(SeriesKind::Scatter)
+///             .marker(Marker::Char('x')),
+///     )
+///     .x_label("time");
+///
+/// let mut canvas = Canvas::new(Vec2::xy(20, 8), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_plot(&plot, Vec2::zero(), Vec2::xy(20, 8));
+/// assert_eq!(canvas.elem(Vec2::xy(0, 0)).unwrap().value, '9');
+/// assert_eq!(canvas.elem(Vec2::xy(1, 5)).unwrap().value, '└');
+/// assert_eq!(canvas.elem(Vec2::xy(2, 0)).unwrap().value, 'x');
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plot {
+    series: Vec<Series>,
+    x_range: Option<(f64, f64)>,
+    y_range: Option<(f64, f64)>,
+    x_label: String,
+    y_label: String,
+}
+
+impl Plot {
+    /// Creates an empty [`Plot`].
+    pub fn new() -> Plot {
+        Plot::default()
+    }
+
+    /// Consumes the receiver and returns a new one with the given [`Series`] drawn over the
+    /// previous ones.
+    pub fn series(mut self, series: Series) -> Plot {
+        self.series.push(series);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose horizontal axis goes from `min` to
+    /// `max`. Points outside of the range are not drawn, and lines to them are cut at the edge
+    /// of the plot.
+    pub fn x_range(mut self, min: f64, max: f64) -> Plot {
+        self.x_range = Some((min, max));
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose vertical axis goes from `min` to `max`.
+    /// Points outside of the range are not drawn, and lines to them are cut at the edge of the
+    /// plot.
+    pub fn y_range(mut self, min: f64, max: f64) -> Plot {
+        self.y_range = Some((min, max));
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with a label centered below the horizontal
+    /// axis.
+    pub fn x_label(mut self, label: &str) -> Plot {
+        self.x_label = label.to_string();
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with a label above the vertical axis.
+    pub fn y_label(mut self, label: &str) -> Plot {
+        self.y_label = label.to_string();
+        self
+    }
+
+    fn range(&self, given: Option<(f64, f64)>, coordinate: fn(&(f64, f64)) -> f64) -> (f64, f64) {
+        let (min, max) = given.unwrap_or_else(|| {
+            self.series
+                .iter()
+                .flat_map(|series| series.points.iter().map(coordinate))
+                .filter(|value| value.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                })
+        });
+        if !min.is_finite() || !max.is_finite() {
+            (0.0, 1.0)
+        } else if min == max {
+            (min - 1.0, max + 1.0)
+        } else {
+            (min, max)
+        }
+    }
+}
+
+/// Formats an axis bound with at most two decimals.
+fn format_bound(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => String::from("0"),
+        _ => text.to_string(),
+    }
+}
+
+/// Returns the first `width` characters of `text`.
+fn truncate(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
+
+/// Clips the segment between `from` and `to` to the box from the origin to `max`, both
+/// included, with the Liang–Barsky algorithm. Returns `None` if no part of the segment is inside.
+fn clip_segment(
+    from: (f64, f64),
+    to: (f64, f64),
+    max: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    if !dx.is_finite() || !dy.is_finite() {
+        return None;
+    }
+    let (mut start, mut end) = (0.0_f64, 1.0_f64);
+    for (p, q) in [
+        (-dx, from.0),
+        (dx, max.0 - from.0),
+        (-dy, from.1),
+        (dy, max.1 - from.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            start = start.max(q / p);
+        } else {
+            end = end.min(q / p);
+        }
+        if start > end {
+            return None;
+        }
+    }
+    let at = |t: f64| (from.0 + t * dx, from.1 + t * dy);
+    Some((at(start), at(end)))
+}
+
+/// Calls `f` for each point of the straight line between `from` and `to`, both included.
+fn for_each_line_point(from: Vec2, to: Vec2, mut f: impl FnMut(Vec2)) {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let (mut point, mut error) = (from, dx + dy);
+    loop {
+        f(point);
+        if point == to {
+            return;
+        }
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            point.x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            point.y += sy;
+        }
+    }
+}
+
+/// Returns the bit of the Braille dot at the given `dot` of a cell, 2 dots wide and 4 high.
+fn braille_bit(dot: Vec2) -> u32 {
+    match (dot.x, dot.y) {
+        (0, 3) => 0x40,
+        (1, 3) => 0x80,
+        (x, y) => 1 << (x * 3 + y),
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws a [`BarChart`] into the rectangle with the given `dimension` whose top-left corner
+    /// is at the given `position`. Bars that do not fit in the rectangle are not drawn.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_bar_chart(
+        &mut self,
+        chart: &BarChart,
+        position: Vec2,
+        dimension: Vec2,
+    ) -> &mut Pencil<'a> {
+        let foreground = self.foreground;
+        let step = (chart.bar_width + chart.gap) as i32;
+        let width = chart.bar_width as i32;
+        match chart.orientation {
+            Orientation::Vertical => {
+                let label_rows = if chart.labels.is_empty() { 0 } else { 1 };
+                let height = dimension.y - label_rows;
+                for (i, &value) in chart.values.iter().enumerate() {
+                    let x = i as i32 * step;
+                    if x + width > dimension.x {
+                        break;
+                    }
+                    if let Some(label) = chart.labels.get(i) {
+                        self.foreground = foreground;
+                        let label = truncate(label, chart.bar_width);
+                        self.draw_text(&label, position + Vec2::xy(x, height));
+                    }
+                    self.foreground = chart.colors_at(i).unwrap_or(foreground);
+                    let eighths = chart.eighths(value, height);
+                    for row in 0..height {
+                        let fill = (eighths - row * 8).clamp(0, 8) as usize;
+                        if fill > 0 {
+                            let glyph = VERTICAL_EIGHTHS[fill];
+                            let pos = position + Vec2::xy(x, height - 1 - row);
+                            self.draw_hline(glyph, pos, width);
+                        }
+                    }
+                }
+            }
+            Orientation::Horizontal => {
+                let label_width = match chart.labels.iter().map(|l| l.chars().count()).max() {
+                    Some(len) => len as i32 + 1,
+                    None => 0,
+                };
+                let length = dimension.x - label_width;
+                for (i, &value) in chart.values.iter().enumerate() {
+                    let y = i as i32 * step;
+                    if y + width > dimension.y {
+                        break;
+                    }
+                    if let Some(label) = chart.labels.get(i) {
+                        self.foreground = foreground;
+                        self.draw_text(label, position + Vec2::y(y));
+                    }
+                    self.foreground = chart.colors_at(i).unwrap_or(foreground);
+                    let eighths = chart.eighths(value, length);
+                    for column in 0..length {
+                        let fill = (eighths - column * 8).clamp(0, 8) as usize;
+                        if fill > 0 {
+                            let glyph = HORIZONTAL_EIGHTHS[fill];
+                            let pos = position + Vec2::xy(label_width + column, y);
+                            self.draw_vline(glyph, pos, width);
+                        }
+                    }
+                }
+            }
+        }
+        self.foreground = foreground;
+        self
+    }
+
+    /// Draws a sparkline of the given `values`: a single row of `width` cells where each value
+    /// is a block whose height goes from the smallest value to the largest one. If there are
+    /// more values than cells, only the last ones are drawn.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::Pencil;
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(4, 1), &VisualElement::default());
+    /// Pencil::new(&mut canvas).draw_sparkline(&[9.0, 0.0, 7.0, 14.0, 3.5], Vec2::zero(), 4);
+    /// let line: String = (0..4).map(|x| canvas.elem(Vec2::x(x)).unwrap().value).collect();
+    /// assert_eq!(line, "▁▅█▃");
+    /// ```
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_sparkline(
+        &mut self,
+        values: &[f64],
+        position: Vec2,
+        width: usize,
+    ) -> &mut Pencil<'a> {
+        let values = &values[values.len().saturating_sub(width)..];
+        let (min, max) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        for (x, &value) in values.iter().enumerate() {
+            let level = if max > min {
+                1 + ((value - min) / (max - min) * 7.0).round() as usize
+            } else {
+                4
+            };
+            self.draw_char(VERTICAL_EIGHTHS[level], position + Vec2::x(x));
+        }
+        self
+    }
+
+    /// Draws a [`Plot`] into the rectangle with the given `dimension` whose top-left corner is
+    /// at the given `position`, including its axes and labels.
+    ///
+    /// Points that are not finite are skipped, and lines are cut at the edges of the plot:
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::{Marker, Pencil, Plot, Series};
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let series = Series::new(&[(0.0, 0.0), (1.0, 1e12), (2.0, 0.0), (2.0, f64::NAN)]);
+    /// let plot = Plot::new()
+    ///     .series(series.marker(Marker::Char('*')))
+    ///     .y_range(0.0, 1.0);
+    ///
+    /// let mut canvas = Canvas::new(Vec2::xy(12, 6), &VisualElement::default());
+    /// Pencil::new(&mut canvas).draw_plot(&plot, Vec2::zero(), Vec2::xy(12, 6));
+    /// assert_eq!(canvas.elem(Vec2::xy(2, 3)).unwrap().value, '*');
+    /// assert_eq!(canvas.elem(Vec2::xy(11, 3)).unwrap().value, '*');
+    /// ```
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_plot(&mut self, plot: &Plot, position: Vec2, dimension: Vec2) -> &mut Pencil<'a> {
+        let foreground = self.foreground;
+        let (x_min, x_max) = plot.range(plot.x_range, |point| point.0);
+        let (y_min, y_max) = plot.range(plot.y_range, |point| point.1);
+        let (y_top, y_bottom) = (format_bound(y_max), format_bound(y_min));
+        let (x_left, x_right) = (format_bound(x_min), format_bound(x_max));
+
+        let axis_x = y_top.chars().count().max(y_bottom.chars().count()) as i32;
+        let top = if plot.y_label.is_empty() { 0 } else { 1 };
+        let bottom = if plot.x_label.is_empty() { 2 } else { 3 };
+        let area = Vec2::xy(dimension.x - axis_x - 1, dimension.y - top - bottom);
+        if area.x <= 0 || area.y <= 0 {
+            return self;
+        }
+        let origin = position + Vec2::xy(axis_x + 1, top);
+        let axis_y = top + area.y;
+
+        self.draw_text(&truncate(&plot.y_label, dimension.x as usize), position)
+            .draw_right_aligned_text(&y_top, position + Vec2::xy(axis_x, top))
+            .draw_right_aligned_text(&y_bottom, position + Vec2::xy(axis_x, axis_y - 1))
+            .draw_vline('│', position + Vec2::xy(axis_x, top), area.y)
+            .draw_char('└', position + Vec2::xy(axis_x, axis_y))
+            .draw_hline('─', origin + Vec2::y(area.y), area.x)
+            .draw_text(&x_left, origin + Vec2::y(area.y + 1))
+            .draw_right_aligned_text(&x_right, position + Vec2::xy(dimension.x, axis_y + 1));
+        if !plot.x_label.is_empty() {
+            let label = truncate(&plot.x_label, area.x as usize);
+            self.draw_center_text(&label, origin + Vec2::xy(area.x / 2, area.y + 2));
+        }
+
+        for series in &plot.series {
+            let resolution = match series.marker {
+                Marker::Braille => Vec2::xy(2, 4),
+                Marker::Char(_) => Vec2::xy(1, 1),
+            };
+            let grid = area * resolution;
+            let max = ((grid.x - 1) as f64, (grid.y - 1) as f64);
+            let to_grid = |&(x, y): &(f64, f64)| {
+                let gx = (x - x_min) / (x_max - x_min) * max.0;
+                let gy = (y_max - y) / (y_max - y_min) * max.1;
+                Some((gx, gy)).filter(|_| gx.is_finite() && gy.is_finite())
+            };
+            let to_dot = |(x, y): (f64, f64)| Vec2::xy(x.round() as i32, y.round() as i32);
+            let inside = |&(x, y): &(f64, f64)| {
+                (0.0..=max.0).contains(&x.round()) && (0.0..=max.1).contains(&y.round())
+            };
+
+            let mut dots = Vec::new();
+            let points: Vec<Option<(f64, f64)>> = series.points.iter().map(to_grid).collect();
+            match series.kind {
+                SeriesKind::Scatter => dots.extend(
+                    points
+                        .iter()
+                        .flatten()
+                        .filter(|p| inside(p))
+                        .map(|&p| to_dot(p)),
+                ),
+                SeriesKind::Line => {
+                    for pair in points.windows(2) {
+                        let (Some(from), Some(to)) = (pair[0], pair[1]) else {
+                            continue;
+                        };
+                        if let Some((from, to)) = clip_segment(from, to, max) {
+                            for_each_line_point(to_dot(from), to_dot(to), |p| dots.push(p));
+                        }
+                    }
+                    if let [Some(point)] = points[..] {
+                        dots.extend(Some(point).filter(inside).map(to_dot));
+                    }
+                }
+            }
+
+            self.foreground = series.color.unwrap_or(foreground);
+            match series.marker {
+                Marker::Char(value) => {
+                    for dot in dots {
+                        self.draw_char(value, origin + dot);
+                    }
+                }
+                Marker::Braille => {
+                    let mut cells = vec![0u32; (area.x * area.y) as usize];
+                    for dot in dots {
+                        let cell = dot / resolution;
+                        cells[(cell.y * area.x + cell.x) as usize] |=
+                            braille_bit(Vec2::xy(dot.x % 2, dot.y % 4));
+                    }
+                    for (i, &bits) in cells.iter().enumerate() {
+                        if let Some(value) = char::from_u32(0x2800 + bits).filter(|_| bits != 0) {
+                            let cell = Vec2::xy(i as i32 % area.x, i as i32 / area.x);
+                            self.draw_char(value, origin + cell);
+                        }
+                    }
+                }
+            }
+        }
+        self.foreground = foreground;
+        self
+    }
+}
@@ -0,0 +1,513 @@
+//! Large banner text rendered from FIGlet fonts.
+
+use super::Pencil;
+use crate::spatial::Vec2;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const BLOCK_FONT: &str = include_str!("fonts/block.flf");
+const MINI_FONT: &str = include_str!("fonts/mini.flf");
+
+/// The characters every FIGlet font defines in order after its header: printable ASCII followed
+/// by the Deutsch characters `ÄÖÜäöüß`, which may be missing in some fonts.
+const REQUIRED_CHARS: &str =
+    " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+const DEUTSCH_CHARS: &str = "ÄÖÜäöüß";
+
+/// Smushing rule: two equal characters are smushed into one.
+pub const SMUSH_EQUAL: u8 = 1;
+/// Smushing rule: an underscore is replaced by a `|/\[]{}()<>` character.
+pub const SMUSH_UNDERSCORE: u8 = 2;
+/// Smushing rule: from `|`, `/\`, `[]`, `{}`, `()` and `<>`, the latter class wins.
+pub const SMUSH_HIERARCHY: u8 = 4;
+/// Smushing rule: opposite brackets, such as `[]` or `)(`, are smushed into `|`.
+pub const SMUSH_OPPOSITE_PAIR: u8 = 8;
+/// Smushing rule: `/\` becomes `|`, `\/` becomes `Y` and `><` becomes `X`.
+pub const SMUSH_BIG_X: u8 = 16;
+/// Smushing rule: two hardblanks are smushed into one.
+pub const SMUSH_HARDBLANK: u8 = 32;
+
+/// How the characters of a banner are put together horizontally.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FigletLayout {
+    /// Each character takes its whole width, as designed in the font.
+    FullWidth,
+    /// Characters are moved together until they touch.
+    Kerning,
+    /// Characters are moved one cell further than [`FigletLayout::Kerning`], merging the touching
+    /// cells with the given smushing rules (a combination of the `SMUSH_*` constants).
+    ///
+    /// With no rules, the character on the right overwrites the one on the left.
+    Smushing(u8),
+}
+
+/// A font in the FIGlet format (`.flf`) used to draw large banner text.
+///
+/// Fonts can be loaded from a file with [`FigletFont::load`], parsed from memory with
+/// [`FigletFont::parse`] or [`FigletFont::from_bytes`], or taken from the fonts bundled with
+/// `ruscii`: [`FigletFont::block`] and [`FigletFont::mini`].
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{FigletFont, FigletLayout};
+/// #
+/// let mut font = FigletFont::mini();
+/// let banner = font.render("Hi");
+/// assert_eq!(banner.len(), font.height());
+/// assert_eq!(banner[0], "█   █ ▀█▀ ");
+///
+/// font.set_layout(FigletLayout::Kerning);
+/// assert_eq!(font.render("Hi")[0], "█   █▀█▀ ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FigletFont {
+    hardblank: char,
+    height: usize,
+    baseline: usize,
+    layout: FigletLayout,
+    glyphs: HashMap<char, Vec<Vec<char>>>,
+}
+
+impl FigletFont {
+    /// Returns the bundled `block` font, five lines high and made of full blocks.
+    ///
+    /// ```text
+    /// █   █ ███
+    /// █   █  █
+    /// █████  █
+    /// █   █  █
+    /// █   █ ███
+    /// ```
+    pub fn block() -> FigletFont {
+        FigletFont::parse(BLOCK_FONT).unwrap()
+    }
+
+    /// Returns the bundled `mini` font, three lines high and made of half blocks.
+    ///
+    /// ```text
+    /// █   █ ▀█▀
+    /// █▀▀▀█  █
+    /// ▀   ▀ ▀▀▀
+    /// ```
+    pub fn mini() -> FigletFont {
+        FigletFont::parse(MINI_FONT).unwrap()
+    }
+
+    /// Loads a FIGlet font from the file at the given `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<FigletFont, FigletError> {
+        FigletFont::from_bytes(&fs::read(path)?)
+    }
+
+    /// Parses a FIGlet font from the given `bytes`, usually embedded with `include_bytes!`.
+    ///
+    /// Fonts that are not valid UTF-8 are read as Latin-1, the encoding of most classic fonts.
+    pub fn from_bytes(bytes: &[u8]) -> Result<FigletFont, FigletError> {
+        match std::str::from_utf8(bytes) {
+            Ok(data) => FigletFont::parse(data),
+            Err(_) => FigletFont::parse(&bytes.iter().map(|b| *b as char).collect::<String>()),
+        }
+    }
+
+    /// Parses a FIGlet font from the given `data`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`FigletError`] if the header is malformed or if the font ends before defining
+    /// all the printable ASCII characters.
+    pub fn parse(data: &str) -> Result<FigletFont, FigletError> {
+        let mut lines = data.lines();
+        let header = lines.next().ok_or(FigletError::InvalidHeader)?;
+        let hardblank = header
+            .strip_prefix("flf2a")
+            .and_then(|rest| rest.chars().next())
+            .ok_or(FigletError::InvalidHeader)?;
+
+        let params = header
+            .split_whitespace()
+            .skip(1)
+            .map(|param| param.parse::<i64>().map_err(|_| FigletError::InvalidHeader))
+            .collect::<Result<Vec<_>, _>>()?;
+        if params.len() < 5 || params[0] < 1 {
+            return Err(FigletError::InvalidHeader);
+        }
+        let height = params[0] as usize;
+        let baseline = params[1].clamp(1, params[0]) as usize;
+        let layout = match params.get(6) {
+            Some(&full) => {
+                let rules = (full & 63) as u8;
+                if full & 128 != 0 {
+                    FigletLayout::Smushing(rules)
+                } else if full & 64 != 0 {
+                    FigletLayout::Kerning
+                } else {
+                    FigletLayout::FullWidth
+                }
+            }
+            None => match params[3] {
+                old if old < 0 => FigletLayout::FullWidth,
+                0 => FigletLayout::Kerning,
+                old => FigletLayout::Smushing((old & 63) as u8),
+            },
+        };
+
+        for _ in 0..params[4] {
+            lines.next();
+        }
+
+        let mut glyphs = HashMap::new();
+        for value in REQUIRED_CHARS.chars() {
+            let glyph = read_glyph(&mut lines, height).ok_or(FigletError::MissingChar(value))?;
+            glyphs.insert(value, glyph);
+        }
+        for value in DEUTSCH_CHARS.chars() {
+            match read_glyph(&mut lines, height) {
+                Some(glyph) => glyphs.insert(value, glyph),
+                None => break,
+            };
+        }
+        while let Some(tag) = lines.next() {
+            let code = tag.split_whitespace().next().and_then(parse_code);
+            let glyph = read_glyph(&mut lines, height);
+            if let (Some(value), Some(glyph)) = (code, glyph) {
+                glyphs.insert(value, glyph);
+            }
+        }
+
+        Ok(FigletFont {
+            hardblank,
+            height,
+            baseline,
+            layout,
+            glyphs,
+        })
+    }
+
+    /// Puts characters together with the given `layout` instead of the one defined by the font.
+    pub fn set_layout(&mut self, layout: FigletLayout) -> &mut FigletFont {
+        self.layout = layout;
+        self
+    }
+
+    pub fn layout(&self) -> FigletLayout {
+        self.layout
+    }
+
+    /// Returns the number of lines of each row of banner text.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of lines from the top of a character to the baseline of the font.
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    /// Renders `text` as banner lines. Each `'\n'` starts a new row of banner text below the
+    /// previous one. Characters not defined by the font are skipped.
+    pub fn render(&self, text: &str) -> Vec<String> {
+        text.split('\n')
+            .flat_map(|line| self.render_line(line))
+            .map(|line| {
+                line.into_iter()
+                    .map(|c| if c == self.hardblank { ' ' } else { c })
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    /// Returns the width and the height that the rendered `text` would take.
+    pub fn dimension(&self, text: &str) -> Vec2 {
+        let banner = self.render(text);
+        let width = banner.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        Vec2::xy(width, banner.len())
+    }
+
+    fn render_line(&self, text: &str) -> Vec<Vec<char>> {
+        let mut output: Vec<Vec<char>> = vec![Vec::new(); self.height];
+        let mut previous_width = 0;
+        for value in text.chars() {
+            let glyph = match self.glyphs.get(&value) {
+                Some(glyph) => glyph,
+                None => continue,
+            };
+            let width = glyph.iter().map(|row| row.len()).max().unwrap_or(0);
+            let amount = self.smush_amount(&output, glyph, previous_width, width);
+            for (line, row) in output.iter_mut().zip(glyph) {
+                for (i, value) in row.iter().enumerate() {
+                    let column = line.len() as isize - amount as isize + i as isize;
+                    if column < 0 {
+                        continue;
+                    }
+                    let column = column as usize;
+                    if column < line.len() {
+                        let merged = self.smush(line[column], *value, previous_width, width);
+                        line[column] = merged.unwrap_or(*value);
+                    } else {
+                        line.push(*value);
+                    }
+                }
+            }
+            previous_width = width;
+        }
+        output
+    }
+
+    /// Computes how many columns a glyph can be moved to the left over the current output.
+    fn smush_amount(
+        &self,
+        output: &[Vec<char>],
+        glyph: &[Vec<char>],
+        previous_width: usize,
+        width: usize,
+    ) -> usize {
+        if self.layout == FigletLayout::FullWidth {
+            return 0;
+        }
+        let mut amount = usize::MAX;
+        for (line, row) in output.iter().zip(glyph) {
+            let line_blanks = line.iter().rev().take_while(|c| **c == ' ').count();
+            let row_blanks = row.iter().take_while(|c| **c == ' ').count();
+            let mut row_amount = line_blanks + row_blanks;
+            let left = line.len().checked_sub(line_blanks + 1).map(|i| line[i]);
+            let right = row.get(row_blanks);
+            match (left, right) {
+                (None, _) => row_amount += 1,
+                (Some(left), Some(right)) => {
+                    if self.smush(left, *right, previous_width, width).is_some() {
+                        row_amount += 1;
+                    }
+                }
+                (Some(_), None) => (),
+            }
+            amount = amount.min(row_amount);
+        }
+        amount
+            .min(width)
+            .min(output.first().map_or(0, |line| line.len()))
+    }
+
+    /// Merges two overlapping characters, returning [`None`] if they cannot be merged.
+    fn smush(&self, left: char, right: char, previous_width: usize, width: usize) -> Option<char> {
+        if left == ' ' {
+            return Some(right);
+        }
+        if right == ' ' {
+            return Some(left);
+        }
+        if previous_width < 2 || width < 2 {
+            return None;
+        }
+        let rules = match self.layout {
+            FigletLayout::Smushing(rules) => rules,
+            _ => return None,
+        };
+        if rules == 0 {
+            return Some(if right == self.hardblank { left } else { right });
+        }
+        if left == self.hardblank || right == self.hardblank {
+            let both = left == self.hardblank && right == self.hardblank;
+            return if both && rules & SMUSH_HARDBLANK != 0 {
+                Some(left)
+            } else {
+                None
+            };
+        }
+        if rules & SMUSH_EQUAL != 0 && left == right {
+            return Some(left);
+        }
+        if rules & SMUSH_UNDERSCORE != 0 {
+            const BORDERS: &str = "|/\\[]{}()<>";
+            if left == '_' && BORDERS.contains(right) {
+                return Some(right);
+            }
+            if right == '_' && BORDERS.contains(left) {
+                return Some(left);
+            }
+        }
+        if rules & SMUSH_HIERARCHY != 0 {
+            const CLASSES: [&str; 6] = ["|", "/\\", "[]", "{}", "()", "<>"];
+            let class = |c: char| CLASSES.iter().position(|class| class.contains(c));
+            if let (Some(l), Some(r)) = (class(left), class(right)) {
+                if l != r {
+                    return Some(if l > r { left } else { right });
+                }
+            }
+        }
+        if rules & SMUSH_OPPOSITE_PAIR != 0 {
+            const PAIRS: [&str; 6] = ["[]", "][", "{}", "}{", "()", ")("];
+            let pair: String = [left, right].iter().collect();
+            if PAIRS.contains(&pair.as_str()) {
+                return Some('|');
+            }
+        }
+        if rules & SMUSH_BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => (),
+            }
+        }
+        None
+    }
+}
+
+/// Reads the next `height` lines of a glyph, removing the end marks.
+fn read_glyph<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    height: usize,
+) -> Option<Vec<Vec<char>>> {
+    let mut glyph = Vec::with_capacity(height);
+    for _ in 0..height {
+        let line = lines.next()?.trim_end();
+        let end_mark = line.chars().last();
+        let line = match end_mark {
+            Some(mark) => line.trim_end_matches(mark),
+            None => line,
+        };
+        glyph.push(line.chars().collect());
+    }
+    Some(glyph)
+}
+
+/// Parses the code of a code-tagged character, in decimal, octal (`0` prefix) or hexadecimal
+/// (`0x` prefix) notation.
+fn parse_code(code: &str) -> Option<char> {
+    let value = if let Some(hex) = code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else if code.len() > 1 && code.starts_with('0') {
+        u32::from_str_radix(&code[1..], 8).ok()?
+    } else {
+        code.parse::<u32>().ok()?
+    };
+    std::char::from_u32(value)
+}
+
+/// The reason why a FIGlet font could not be loaded.
+#[derive(Debug)]
+pub enum FigletError {
+    Io(io::Error),
+    InvalidHeader,
+    MissingChar(char),
+}
+
+impl Display for FigletError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FigletError::Io(error) => write!(f, "cannot read the font: {}", error),
+            FigletError::InvalidHeader => write!(f, "the font header is not a valid flf2a header"),
+            FigletError::MissingChar(value) => {
+                write!(f, "the font ends before defining the character {:?}", value)
+            }
+        }
+    }
+}
+
+impl Error for FigletError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FigletError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FigletError {
+    fn from(error: io::Error) -> Self {
+        FigletError::Io(error)
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws `text` as banner text with the given FIGlet `font`, with its top-left corner at the
+    /// given `position`.
+    ///
+    /// Banner text wider than the [`Canvas`](crate::terminal::Canvas) is cut off at its edges
+    /// instead of wrapping to the next line:
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::{FigletFont, Pencil};
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let font = FigletFont::block();
+    /// let height = font.height() as i32;
+    /// let mut canvas = Canvas::new(Vec2::xy(8, height + 1), &VisualElement::default());
+    /// Pencil::new(&mut canvas)
+    ///     .draw_filled_rect('.', Vec2::zero(), Vec2::xy(8, height + 1))
+    ///     .draw_figlet_text("WIDE", &font, Vec2::zero());
+    ///
+    /// let below: String = (0..8).map(|x| canvas.elem(Vec2::xy(x, height)).unwrap().value).collect();
+    /// assert_eq!(below, "........");
+    /// ```
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_figlet_text(
+        &mut self,
+        text: &str,
+        font: &FigletFont,
+        position: Vec2,
+    ) -> &mut Pencil<'a> {
+        self.draw_figlet_rows(text, font, position, |_| 0)
+    }
+
+    /// Draws `text` as banner text with the given FIGlet `font`, horizontally centered at the
+    /// given `position`. Each row of banner text is centered on its own.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_center_figlet_text(
+        &mut self,
+        text: &str,
+        font: &FigletFont,
+        position: Vec2,
+    ) -> &mut Pencil<'a> {
+        self.draw_figlet_rows(text, font, position, |width| width / 2)
+    }
+
+    /// Draws `text` as right-aligned banner text with the given FIGlet `font`, ending at the
+    /// given `position`. Each row of banner text is aligned on its own.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_right_aligned_figlet_text(
+        &mut self,
+        text: &str,
+        font: &FigletFont,
+        position: Vec2,
+    ) -> &mut Pencil<'a> {
+        self.draw_figlet_rows(text, font, position, |width| width)
+    }
+
+    /// Draws each row of banner text displaced to the left by the result of `anchor`, which
+    /// receives the visible width of the row. Cells outside of the canvas are skipped.
+    fn draw_figlet_rows(
+        &mut self,
+        text: &str,
+        font: &FigletFont,
+        position: Vec2,
+        anchor: impl Fn(usize) -> usize,
+    ) -> &mut Pencil<'a> {
+        let mut y = 0;
+        for row in text.split('\n') {
+            let lines = font.render(row);
+            let width = lines
+                .iter()
+                .map(|line| line.trim_end().chars().count())
+                .max()
+                .unwrap_or(0);
+            let left = self.origin + position - Vec2::x(anchor(width));
+            for line in &lines {
+                for (x, value) in line.chars().take(width).enumerate() {
+                    self.draw_element(left + Vec2::xy(x, y), value);
+                }
+                y += 1;
+            }
+        }
+        self
+    }
+}
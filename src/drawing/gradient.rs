@@ -0,0 +1,193 @@
+//! Multi-stop color gradients.
+
+use super::Pencil;
+use crate::spatial::Vec2;
+use crate::terminal::{Color, Hsl};
+use crate::tween::Lerp;
+
+/// The way a gradient is laid over a rectangle.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GradientDirection {
+    /// From the left side to the right side.
+    Horizontal,
+    /// From the top side to the bottom side.
+    Vertical,
+    /// From the center to the corners.
+    Radial,
+}
+
+/// A sequence of colors placed at positions, or stops, from `0.0` to `1.0`, with the colors in
+/// between interpolated.
+///
+/// Colors are interpolated by their RGB components, or by their [`Hsl`] components with
+/// [`Gradient::hsl`], and result in [`Color::Rgb`]s that the [`Window`] quantizes to its
+/// [`ColorDepth`].
+///
+/// [`Window`]: crate::terminal::Window
+/// [`ColorDepth`]: crate::terminal::ColorDepth
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{Gradient, GradientDirection, Pencil};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, Color, Rgb, VisualElement};
+/// #
+/// let gradient = Gradient::new(&[Color::Red, Color::Blue]);
+/// assert_eq!(gradient.at(0.5), Color::Rgb(Rgb::new(128, 0, 128)));
+///
+/// let mut canvas = Canvas::new(Vec2::xy(5, 1), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_gradient_rect(
+///     ' ',
+///     Vec2::zero(),
+///     Vec2::xy(5, 1),
+///     &gradient,
+///     GradientDirection::Horizontal,
+/// );
+/// assert_eq!(canvas.elem(Vec2::zero()).unwrap().background.rgb(), Rgb::new(255, 0, 0));
+/// assert_eq!(canvas.elem(Vec2::x(4)).unwrap().background.rgb(), Rgb::new(0, 0, 255));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Color)>,
+    hsl: bool,
+}
+
+impl Gradient {
+    /// Creates a [`Gradient`] with the given `colors` evenly spaced.
+    pub fn new(colors: &[Color]) -> Gradient {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        let stops: Vec<(f32, Color)> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, &color)| (i as f32 / last, color))
+            .collect();
+        Gradient::from_stops(&stops)
+    }
+
+    /// Creates a [`Gradient`] from the given `(position, color)` stops. Positions are clamped
+    /// to `[0.0, 1.0]` and do not need to be sorted.
+    pub fn from_stops(stops: &[(f32, Color)]) -> Gradient {
+        let mut stops: Vec<(f32, Color)> = stops
+            .iter()
+            .map(|&(position, color)| (position.clamp(0.0, 1.0), color))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Gradient { stops, hsl: false }
+    }
+
+    /// Consumes the receiver and returns a new one that interpolates its colors by their [`Hsl`]
+    /// components, going through the hues between them, or by their RGB components.
+    pub fn hsl(mut self, enable: bool) -> Gradient {
+        self.hsl = enable;
+        self
+    }
+
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Returns the color at the given `position`, which is clamped to `[0.0, 1.0]`. A gradient
+    /// without stops is black.
+    pub fn at(&self, position: f32) -> Color {
+        let position = position.clamp(0.0, 1.0);
+        let next = self.stops.iter().position(|stop| stop.0 >= position);
+        let (from, to) = match next {
+            None => match self.stops.last() {
+                Some(&(_, color)) => return color,
+                None => return Color::Black,
+            },
+            Some(0) => return self.stops[0].1,
+            Some(next) => (self.stops[next - 1], self.stops[next]),
+        };
+        let t = if to.0 > from.0 {
+            (position - from.0) / (to.0 - from.0)
+        } else {
+            1.0
+        };
+        if self.hsl {
+            Color::from(Hsl::from(from.1).lerp(&Hsl::from(to.1), t))
+        } else {
+            from.1.lerp(&to.1, t)
+        }
+    }
+
+    /// Returns the color of the cell at `pos` in a rectangle with the given `dimension`.
+    fn at_cell(&self, pos: Vec2, dimension: Vec2, direction: GradientDirection) -> Color {
+        let ratio = |value: i32, size: i32| match size {
+            0 | 1 => 0.0,
+            size => value as f32 / (size - 1) as f32,
+        };
+        match direction {
+            GradientDirection::Horizontal => self.at(ratio(pos.x, dimension.x)),
+            GradientDirection::Vertical => self.at(ratio(pos.y, dimension.y)),
+            GradientDirection::Radial => {
+                // Cells are about twice as high as they are wide.
+                let distance = |x: f32, y: f32| (x * x + 4.0 * y * y).sqrt();
+                let center_x = (dimension.x - 1) as f32 / 2.0;
+                let center_y = (dimension.y - 1) as f32 / 2.0;
+                let max = distance(center_x, center_y);
+                match max {
+                    max if max > 0.0 => {
+                        let d = distance(pos.x as f32 - center_x, pos.y as f32 - center_y);
+                        self.at(d / max)
+                    }
+                    _ => self.at(0.0),
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws a filled rectangle whose background follows the given `gradient`. The given
+    /// `position` sets the position of the top-left corner of the rectangle. The rectangle is
+    /// composed of the given `fill` characters, drawn with the foreground of the [`Pencil`].
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_gradient_rect(
+        &mut self,
+        fill: char,
+        position: Vec2,
+        dimension: Vec2,
+        gradient: &Gradient,
+        direction: GradientDirection,
+    ) -> &mut Pencil<'a> {
+        let background = self.background;
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let pos = Vec2::xy(x, y);
+                self.background = gradient.at_cell(pos, dimension, direction);
+                self.draw_element(self.origin + position + pos, fill);
+            }
+        }
+        self.background = background;
+        self
+    }
+
+    /// Draws a string at the given `position` whose characters are colored following the given
+    /// `gradient`. The text can have several lines, and the gradient spans the rectangle that
+    /// they cover.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_gradient_text(
+        &mut self,
+        text: &str,
+        position: Vec2,
+        gradient: &Gradient,
+        direction: GradientDirection,
+    ) -> &mut Pencil<'a> {
+        let foreground = self.foreground;
+        let width = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let dimension = Vec2::xy(width, text.lines().count());
+        for (y, line) in text.lines().enumerate() {
+            for (x, value) in line.chars().enumerate() {
+                let pos = Vec2::xy(x, y);
+                self.foreground = gradient.at_cell(pos, dimension, direction);
+                self.draw_element(self.origin + position + pos, value);
+            }
+        }
+        self.foreground = foreground;
+        self
+    }
+}
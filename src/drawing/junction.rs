@@ -0,0 +1,178 @@
+//! Merging of box-drawing characters where lines cross or touch.
+
+/// The box-drawing characters that can be merged, with the weight of their arms in the order
+/// up, right, down and left: `0` for no arm, `1` for light, `2` for heavy and `3` for double.
+#[rustfmt::skip]
+const BOX_GLYPHS: [(char, u8, u8, u8, u8); 109] = [
+    ('─', 0, 1, 0, 1), // light horizontal
+    ('━', 0, 2, 0, 2), // heavy horizontal
+    ('│', 1, 0, 1, 0), // light vertical
+    ('┃', 2, 0, 2, 0), // heavy vertical
+    ('┌', 0, 1, 1, 0), // light down and right
+    ('┍', 0, 2, 1, 0), // down light and right heavy
+    ('┎', 0, 1, 2, 0), // down heavy and right light
+    ('┏', 0, 2, 2, 0), // heavy down and right
+    ('┐', 0, 0, 1, 1), // light down and left
+    ('┑', 0, 0, 1, 2), // down light and left heavy
+    ('┒', 0, 0, 2, 1), // down heavy and left light
+    ('┓', 0, 0, 2, 2), // heavy down and left
+    ('└', 1, 1, 0, 0), // light up and right
+    ('┕', 1, 2, 0, 0), // up light and right heavy
+    ('┖', 2, 1, 0, 0), // up heavy and right light
+    ('┗', 2, 2, 0, 0), // heavy up and right
+    ('┘', 1, 0, 0, 1), // light up and left
+    ('┙', 1, 0, 0, 2), // up light and left heavy
+    ('┚', 2, 0, 0, 1), // up heavy and left light
+    ('┛', 2, 0, 0, 2), // heavy up and left
+    ('├', 1, 1, 1, 0), // light vertical and right
+    ('┝', 1, 2, 1, 0), // vertical light and right heavy
+    ('┞', 2, 1, 1, 0), // up heavy and right down light
+    ('┟', 1, 1, 2, 0), // down heavy and right up light
+    ('┠', 2, 1, 2, 0), // vertical heavy and right light
+    ('┡', 2, 2, 1, 0), // down light and right up heavy
+    ('┢', 1, 2, 2, 0), // up light and right down heavy
+    ('┣', 2, 2, 2, 0), // heavy vertical and right
+    ('┤', 1, 0, 1, 1), // light vertical and left
+    ('┥', 1, 0, 1, 2), // vertical light and left heavy
+    ('┦', 2, 0, 1, 1), // up heavy and left down light
+    ('┧', 1, 0, 2, 1), // down heavy and left up light
+    ('┨', 2, 0, 2, 1), // vertical heavy and left light
+    ('┩', 2, 0, 1, 2), // down light and left up heavy
+    ('┪', 1, 0, 2, 2), // up light and left down heavy
+    ('┫', 2, 0, 2, 2), // heavy vertical and left
+    ('┬', 0, 1, 1, 1), // light down and horizontal
+    ('┭', 0, 1, 1, 2), // left heavy and right down light
+    ('┮', 0, 2, 1, 1), // right heavy and left down light
+    ('┯', 0, 2, 1, 2), // down light and horizontal heavy
+    ('┰', 0, 1, 2, 1), // down heavy and horizontal light
+    ('┱', 0, 1, 2, 2), // right light and left down heavy
+    ('┲', 0, 2, 2, 1), // left light and right down heavy
+    ('┳', 0, 2, 2, 2), // heavy down and horizontal
+    ('┴', 1, 1, 0, 1), // light up and horizontal
+    ('┵', 1, 1, 0, 2), // left heavy and right up light
+    ('┶', 1, 2, 0, 1), // right heavy and left up light
+    ('┷', 1, 2, 0, 2), // up light and horizontal heavy
+    ('┸', 2, 1, 0, 1), // up heavy and horizontal light
+    ('┹', 2, 1, 0, 2), // right light and left up heavy
+    ('┺', 2, 2, 0, 1), // left light and right up heavy
+    ('┻', 2, 2, 0, 2), // heavy up and horizontal
+    ('┼', 1, 1, 1, 1), // light vertical and horizontal
+    ('┽', 1, 1, 1, 2), // left heavy and right vertical light
+    ('┾', 1, 2, 1, 1), // right heavy and left vertical light
+    ('┿', 1, 2, 1, 2), // vertical light and horizontal heavy
+    ('╀', 2, 1, 1, 1), // up heavy and down horizontal light
+    ('╁', 1, 1, 2, 1), // down heavy and up horizontal light
+    ('╂', 2, 1, 2, 1), // vertical heavy and horizontal light
+    ('╃', 2, 1, 1, 2), // left up heavy and right down light
+    ('╄', 2, 2, 1, 1), // right up heavy and left down light
+    ('╅', 1, 1, 2, 2), // left down heavy and right up light
+    ('╆', 1, 2, 2, 1), // right down heavy and left up light
+    ('╇', 2, 2, 1, 2), // down light and up horizontal heavy
+    ('╈', 1, 2, 2, 2), // up light and down horizontal heavy
+    ('╉', 2, 1, 2, 2), // right light and left vertical heavy
+    ('╊', 2, 2, 2, 1), // left light and right vertical heavy
+    ('╋', 2, 2, 2, 2), // heavy vertical and horizontal
+    ('═', 0, 3, 0, 3), // double horizontal
+    ('║', 3, 0, 3, 0), // double vertical
+    ('╒', 0, 3, 1, 0), // down single and right double
+    ('╓', 0, 1, 3, 0), // down double and right single
+    ('╔', 0, 3, 3, 0), // double down and right
+    ('╕', 0, 0, 1, 3), // down single and left double
+    ('╖', 0, 0, 3, 1), // down double and left single
+    ('╗', 0, 0, 3, 3), // double down and left
+    ('╘', 1, 3, 0, 0), // up single and right double
+    ('╙', 3, 1, 0, 0), // up double and right single
+    ('╚', 3, 3, 0, 0), // double up and right
+    ('╛', 1, 0, 0, 3), // up single and left double
+    ('╜', 3, 0, 0, 1), // up double and left single
+    ('╝', 3, 0, 0, 3), // double up and left
+    ('╞', 1, 3, 1, 0), // vertical single and right double
+    ('╟', 3, 1, 3, 0), // vertical double and right single
+    ('╠', 3, 3, 3, 0), // double vertical and right
+    ('╡', 1, 0, 1, 3), // vertical single and left double
+    ('╢', 3, 0, 3, 1), // vertical double and left single
+    ('╣', 3, 0, 3, 3), // double vertical and left
+    ('╤', 0, 3, 1, 3), // down single and horizontal double
+    ('╥', 0, 1, 3, 1), // down double and horizontal single
+    ('╦', 0, 3, 3, 3), // double down and horizontal
+    ('╧', 1, 3, 0, 3), // up single and horizontal double
+    ('╨', 3, 1, 0, 1), // up double and horizontal single
+    ('╩', 3, 3, 0, 3), // double up and horizontal
+    ('╪', 1, 3, 1, 3), // vertical single and horizontal double
+    ('╫', 3, 1, 3, 1), // vertical double and horizontal single
+    ('╬', 3, 3, 3, 3), // double vertical and horizontal
+    ('╴', 0, 0, 0, 1), // light left
+    ('╵', 1, 0, 0, 0), // light up
+    ('╶', 0, 1, 0, 0), // light right
+    ('╷', 0, 0, 1, 0), // light down
+    ('╸', 0, 0, 0, 2), // heavy left
+    ('╹', 2, 0, 0, 0), // heavy up
+    ('╺', 0, 2, 0, 0), // heavy right
+    ('╻', 0, 0, 2, 0), // heavy down
+    ('╼', 0, 2, 0, 1), // light left and heavy right
+    ('╽', 1, 0, 2, 0), // light up and heavy down
+    ('╾', 0, 1, 0, 2), // heavy left and light right
+    ('╿', 2, 0, 1, 0), // heavy up and light down
+];
+
+/// Rounded corners and the light corners with the same arms.
+const ROUND_CORNERS: [(char, char); 4] = [('╭', '┌'), ('╮', '┐'), ('╯', '┘'), ('╰', '└')];
+
+/// Returns the weight of the arms of a box-drawing character in the order up, right, down and
+/// left, or [`None`] if `value` is not a mergeable box-drawing character.
+pub(crate) fn arms(value: char) -> Option<[u8; 4]> {
+    let value = ROUND_CORNERS
+        .iter()
+        .find(|(round, _)| *round == value)
+        .map_or(value, |(_, corner)| *corner);
+    BOX_GLYPHS
+        .iter()
+        .find(|glyph| glyph.0 == value)
+        .map(|&(_, up, right, down, left)| [up, right, down, left])
+}
+
+/// Returns `true` if `value` is a rounded corner.
+pub(crate) fn is_rounded(value: char) -> bool {
+    ROUND_CORNERS.iter().any(|(round, _)| *round == value)
+}
+
+/// Returns the box-drawing character with the given arms, if it exists. Light corners are
+/// returned rounded if `rounded` is set.
+pub(crate) fn glyph(arms: [u8; 4], rounded: bool) -> Option<char> {
+    let [up, right, down, left] = arms;
+    let value = BOX_GLYPHS
+        .iter()
+        .find(|glyph| (glyph.1, glyph.2, glyph.3, glyph.4) == (up, right, down, left))
+        .map(|glyph| glyph.0)?;
+    match ROUND_CORNERS.iter().find(|(_, corner)| *corner == value) {
+        Some((round, _)) if rounded => Some(*round),
+        _ => Some(value),
+    }
+}
+
+/// Merges the box-drawing character `above` drawn over `below`, joining the arms of both.
+///
+/// Arms of `above` take precedence over the ones of `below` in the same direction. If there is no
+/// character for the mix of weights, all the arms take the heaviest weight of `above`. If any of
+/// the characters is not a box-drawing character, `above` is returned.
+pub(crate) fn merge(below: char, above: char) -> char {
+    let (below_arms, above_arms) = match (arms(below), arms(above)) {
+        (Some(below_arms), Some(above_arms)) => (below_arms, above_arms),
+        _ => return above,
+    };
+
+    let mut merged = below_arms;
+    for (arm, above_arm) in merged.iter_mut().zip(above_arms.iter()) {
+        if *above_arm != 0 {
+            *arm = *above_arm;
+        }
+    }
+
+    let rounded = is_rounded(below) || is_rounded(above);
+    glyph(merged, rounded)
+        .or_else(|| {
+            let weight = *above_arms.iter().max().unwrap();
+            glyph(merged.map(|arm| if arm != 0 { weight } else { 0 }), rounded)
+        })
+        .unwrap_or(above)
+}
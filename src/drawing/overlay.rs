@@ -0,0 +1,157 @@
+//! Operations that change the colors of the cells already drawn.
+
+use super::Pencil;
+use crate::spatial::Vec2;
+use crate::terminal::VisualElement;
+
+impl<'a> Pencil<'a> {
+    fn transform_rect(
+        &mut self,
+        position: Vec2,
+        dimension: Vec2,
+        f: impl FnMut(&mut VisualElement),
+    ) -> &mut Pencil<'a> {
+        self.canvas.transform(self.origin + position, dimension, f);
+        self
+    }
+
+    /// Darkens the foreground and background colors of the cells already drawn in the rectangle
+    /// with the given `dimension` whose top-left corner is at the given `position`. The `amount`
+    /// goes from `0.0` (unchanged) to `1.0` (black). See [`Color::darken`].
+    ///
+    /// This is useful to dim the game behind a pause menu:
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::{Pencil, RectCharset};
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, Color, Rgb, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(20, 10), &VisualElement::default());
+    /// let mut pencil = Pencil::new(&mut canvas);
+    /// pencil
+    ///     .set_foreground(Color::Red)
+    ///     .draw_text("game", Vec2::xy(1, 1))
+    ///     .darken_rect(Vec2::zero(), Vec2::xy(20, 10), 0.5)
+    ///     .set_foreground(Color::White)
+    ///     .draw_rect(&RectCharset::simple_lines(), Vec2::xy(5, 3), Vec2::xy(10, 4));
+    ///
+    /// assert_eq!(canvas.elem(Vec2::xy(1, 1)).unwrap().foreground.rgb(), Rgb::new(128, 0, 0));
+    /// assert_eq!(canvas.elem(Vec2::xy(5, 3)).unwrap().foreground, Color::White);
+    /// ```
+    ///
+    /// Returns the receiver for chaining.
+    ///
+    /// [`Color::darken`]: crate::terminal::Color::darken
+    pub fn darken_rect(&mut self, position: Vec2, dimension: Vec2, amount: f32) -> &mut Pencil<'a> {
+        self.transform_rect(position, dimension, |element| {
+            element.foreground = element.foreground.darken(amount);
+            element.background = element.background.darken(amount);
+        })
+    }
+
+    /// Lightens the foreground and background colors of the cells already drawn in the
+    /// rectangle with the given `dimension` whose top-left corner is at the given `position`.
+    /// The `amount` goes from `0.0` (unchanged) to `1.0` (white). See [`Color::lighten`].
+    ///
+    /// Returns the receiver for chaining.
+    ///
+    /// [`Color::lighten`]: crate::terminal::Color::lighten
+    pub fn lighten_rect(
+        &mut self,
+        position: Vec2,
+        dimension: Vec2,
+        amount: f32,
+    ) -> &mut Pencil<'a> {
+        self.transform_rect(position, dimension, |element| {
+            element.foreground = element.foreground.lighten(amount);
+            element.background = element.background.lighten(amount);
+        })
+    }
+
+    /// Reduces the saturation of the foreground and background colors of the cells already
+    /// drawn in the rectangle with the given `dimension` whose top-left corner is at the given
+    /// `position`. The `amount` goes from `0.0` (unchanged) to `1.0` (gray). See
+    /// [`Color::desaturate`].
+    ///
+    /// Returns the receiver for chaining.
+    ///
+    /// [`Color::desaturate`]: crate::terminal::Color::desaturate
+    pub fn desaturate_rect(
+        &mut self,
+        position: Vec2,
+        dimension: Vec2,
+        amount: f32,
+    ) -> &mut Pencil<'a> {
+        self.transform_rect(position, dimension, |element| {
+            element.foreground = element.foreground.desaturate(amount);
+            element.background = element.background.desaturate(amount);
+        })
+    }
+
+    /// Sets the background of the cells in the rectangle with the given `dimension` whose
+    /// top-left corner is at the given `position` to the background of the [`Pencil`], keeping
+    /// their characters and foreground.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn fill_background(&mut self, position: Vec2, dimension: Vec2) -> &mut Pencil<'a> {
+        let background = self.background;
+        self.transform_rect(position, dimension, |element| {
+            element.background = background;
+        })
+    }
+
+    /// Draws the drop shadow of a panel with the given `dimension` whose top-left corner is at
+    /// the given `position`, darkening by `amount` the cells that the panel would cover if it
+    /// was moved by `offset`, except the ones under the panel itself. An `offset` of
+    /// `Vec2::xy(2, 1)` looks even on most terminals, whose cells are twice as high as wide.
+    ///
+    /// Since the cells under the panel are not changed, the shadow can be drawn before or after
+    /// the panel.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::Pencil;
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, Color, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(10, 6), &VisualElement::default());
+    /// Pencil::new(&mut canvas)
+    ///     .set_background(Color::Blue)
+    ///     .draw_filled_rect(' ', Vec2::xy(1, 1), Vec2::xy(5, 3))
+    ///     .draw_shadow(Vec2::xy(1, 1), Vec2::xy(5, 3), Vec2::xy(2, 1), 1.0);
+    ///
+    /// assert_eq!(canvas.elem(Vec2::xy(1, 1)).unwrap().background, Color::Blue);
+    /// assert_eq!(canvas.elem(Vec2::xy(5, 3)).unwrap().background, Color::Blue);
+    /// assert_eq!(canvas.elem(Vec2::xy(6, 4)).unwrap().foreground.rgb().r, 0);
+    /// ```
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_shadow(
+        &mut self,
+        position: Vec2,
+        dimension: Vec2,
+        offset: Vec2,
+        amount: f32,
+    ) -> &mut Pencil<'a> {
+        let panel = self.origin + position;
+        let under_panel = |pos: Vec2| {
+            panel.x <= pos.x
+                && panel.y <= pos.y
+                && pos.x < panel.x + dimension.x
+                && pos.y < panel.y + dimension.y
+        };
+        let shadow = panel + offset;
+        for y in shadow.y..shadow.y + dimension.y {
+            for x in shadow.x..shadow.x + dimension.x {
+                let pos = Vec2::xy(x, y);
+                if under_panel(pos) {
+                    continue;
+                }
+                if let Some(element) = self.canvas.elem_mut(pos) {
+                    element.foreground = element.foreground.darken(amount);
+                    element.background = element.background.darken(amount);
+                }
+            }
+        }
+        self
+    }
+}
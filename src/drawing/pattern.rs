@@ -0,0 +1,223 @@
+//! Lines and borders made of repeating sequences of characters.
+
+use super::{Pencil, RectCharset};
+use crate::spatial::Vec2;
+
+/// Pairs of light line characters and their heavy counterparts.
+const HEAVY_LINES: [(char, char); 8] = [
+    ('─', '━'),
+    ('│', '┃'),
+    ('╌', '╍'),
+    ('┄', '┅'),
+    ('┈', '┉'),
+    ('╎', '╏'),
+    ('┆', '┇'),
+    ('┊', '┋'),
+];
+
+/// A sequence of characters repeated along a line.
+///
+/// ```rust
+/// # use ruscii::drawing::{LinePattern, Pencil};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let mut canvas = Canvas::new(Vec2::xy(8, 1), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_patterned_hline(&LinePattern::new("─ "), Vec2::zero(), 8);
+/// let line: String = (0..8).map(|x| canvas.elem(Vec2::x(x)).unwrap().value).collect();
+/// assert_eq!(line, "─ ─ ─ ─ ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinePattern {
+    chars: Vec<char>,
+}
+
+impl LinePattern {
+    /// Creates a [`LinePattern`] that repeats the characters of `pattern`. An empty `pattern` is
+    /// taken as a space.
+    pub fn new(pattern: &str) -> LinePattern {
+        match pattern.is_empty() {
+            true => LinePattern::solid(' '),
+            false => LinePattern {
+                chars: pattern.chars().collect(),
+            },
+        }
+    }
+
+    /// Creates a [`LinePattern`] made of a single character.
+    pub fn solid(value: char) -> LinePattern {
+        LinePattern { chars: vec![value] }
+    }
+
+    /// Returns a dashed [`LinePattern`] for horizontal lines, with two dashes per cell: `╌`.
+    pub fn dashed() -> LinePattern {
+        LinePattern::solid('╌')
+    }
+
+    /// Returns a dashed [`LinePattern`] for horizontal lines, with three dashes per cell: `┄`.
+    pub fn triple_dashed() -> LinePattern {
+        LinePattern::solid('┄')
+    }
+
+    /// Returns a dashed [`LinePattern`] for horizontal lines, with four dashes per cell: `┈`.
+    pub fn quadruple_dashed() -> LinePattern {
+        LinePattern::solid('┈')
+    }
+
+    /// Returns a dashed [`LinePattern`] for vertical lines, with two dashes per cell: `╎`.
+    pub fn vertical_dashed() -> LinePattern {
+        LinePattern::solid('╎')
+    }
+
+    /// Returns a dashed [`LinePattern`] for vertical lines, with three dashes per cell: `┆`.
+    pub fn vertical_triple_dashed() -> LinePattern {
+        LinePattern::solid('┆')
+    }
+
+    /// Returns a dashed [`LinePattern`] for vertical lines, with four dashes per cell: `┊`.
+    pub fn vertical_quadruple_dashed() -> LinePattern {
+        LinePattern::solid('┊')
+    }
+
+    /// Returns a [`LinePattern`] for horizontal lines that alternates dashes and dots: `──·`.
+    pub fn dash_dot() -> LinePattern {
+        LinePattern::new("──·")
+    }
+
+    /// Returns a [`LinePattern`] for vertical lines that alternates dashes and dots: `││·`.
+    pub fn vertical_dash_dot() -> LinePattern {
+        LinePattern::new("││·")
+    }
+
+    /// Consumes the receiver and returns a new one with its light box-drawing lines and dashes
+    /// replaced by heavy ones.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::LinePattern;
+    /// #
+    /// assert_eq!(LinePattern::triple_dashed().heavy(), LinePattern::new("┅"));
+    /// assert_eq!(LinePattern::vertical_dash_dot().heavy(), LinePattern::new("┃┃·"));
+    /// ```
+    pub fn heavy(mut self) -> LinePattern {
+        for value in self.chars.iter_mut() {
+            if let Some(&(_, heavy)) = HEAVY_LINES.iter().find(|(light, _)| light == value) {
+                *value = heavy;
+            }
+        }
+        self
+    }
+
+    /// Returns the character at the given `index` along the line.
+    pub fn at(&self, index: usize) -> char {
+        self.chars[index % self.chars.len()]
+    }
+
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+}
+
+impl From<&str> for LinePattern {
+    fn from(pattern: &str) -> Self {
+        LinePattern::new(pattern)
+    }
+}
+
+/// The set of [`LinePattern`]s and corners needed to draw a rectangle with patterned edges.
+///
+/// It can be built from a [`RectCharset`], whose edges become solid patterns, and then changed:
+///
+/// ```rust
+/// # use ruscii::drawing::{BorderPattern, LinePattern, RectCharset};
+/// #
+/// let mut border = BorderPattern::from(&RectCharset::simple_lines());
+/// border.top = LinePattern::new("─┄");
+/// border.bottom = border.top.clone();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderPattern {
+    pub top: LinePattern,
+    pub bottom: LinePattern,
+    pub left: LinePattern,
+    pub right: LinePattern,
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+}
+
+impl From<&RectCharset> for BorderPattern {
+    fn from(charset: &RectCharset) -> Self {
+        BorderPattern {
+            top: LinePattern::solid(charset.top),
+            bottom: LinePattern::solid(charset.bottom),
+            left: LinePattern::solid(charset.left),
+            right: LinePattern::solid(charset.right),
+            top_left: charset.top_left,
+            top_right: charset.top_right,
+            bottom_left: charset.bottom_left,
+            bottom_right: charset.bottom_right,
+        }
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws a horizontal line starting from the given `position` and extending for `size`
+    /// characters rightwards, repeating the given `pattern`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_patterned_hline(
+        &mut self,
+        pattern: &LinePattern,
+        position: Vec2,
+        size: usize,
+    ) -> &mut Pencil<'a> {
+        let elem_pos = self.origin + position;
+        for i in 0..size {
+            self.draw_element(elem_pos + Vec2::x(i), pattern.at(i));
+        }
+        self
+    }
+
+    /// Draws a vertical line starting from the given `position` and extending for `size` lines
+    /// downwards, repeating the given `pattern`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_patterned_vline(
+        &mut self,
+        pattern: &LinePattern,
+        position: Vec2,
+        size: usize,
+    ) -> &mut Pencil<'a> {
+        let elem_pos = self.origin + position;
+        for i in 0..size {
+            self.draw_element(elem_pos + Vec2::y(i), pattern.at(i));
+        }
+        self
+    }
+
+    /// Draws an empty rectangle with the given [`BorderPattern`] and `dimension`. The given
+    /// `position` sets the position of the top-left corner of the rectangle. Each edge starts its
+    /// pattern next to the top or left corner.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_patterned_rect(
+        &mut self,
+        border: &BorderPattern,
+        position: Vec2,
+        dimension: Vec2,
+    ) -> &mut Pencil<'a> {
+        let inner_x = (dimension.x - 2).max(0) as usize;
+        let inner_y = (dimension.y - 2).max(0) as usize;
+        self.move_origin(position)
+            .draw_patterned_hline(&border.top, Vec2::x(1), inner_x)
+            .draw_patterned_hline(&border.bottom, Vec2::xy(1, dimension.y - 1), inner_x)
+            .draw_patterned_vline(&border.left, Vec2::y(1), inner_y)
+            .draw_patterned_vline(&border.right, Vec2::xy(dimension.x - 1, 1), inner_y)
+            .draw_char(border.top_left, Vec2::xy(0, 0))
+            .draw_char(border.top_right, Vec2::x(dimension.x - 1))
+            .draw_char(border.bottom_left, Vec2::y(dimension.y - 1))
+            .draw_char(border.bottom_right, dimension - Vec2::xy(1, 1))
+            .move_origin(-position)
+    }
+}
@@ -0,0 +1,351 @@
+//! Multi-line images made of characters, with transparency and colors.
+
+use super::styled::{apply_option, Span};
+use super::transform::{self, Rotation};
+use super::Pencil;
+use crate::spatial::Vec2;
+use crate::terminal::{Color, Style};
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A cell of a [`Sprite`].
+///
+/// Options set to [`None`] are inherited from the [`Pencil`] that draws the sprite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpriteCell {
+    pub value: char,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub style: Option<Style>,
+}
+
+impl SpriteCell {
+    /// Creates a [`SpriteCell`] that inherits all its options from the [`Pencil`].
+    pub fn new(value: char) -> SpriteCell {
+        SpriteCell {
+            value,
+            foreground: None,
+            background: None,
+            style: None,
+        }
+    }
+}
+
+/// A rectangular image made of characters, where each cell can have its own colors and
+/// [`Style`] or be transparent.
+///
+/// Sprites are usually loaded from a text file with [`Sprite::load`] and colored with a
+/// [`ColorMap`], and drawn with [`Pencil::draw_sprite`]. Transparent cells leave the [`Canvas`]
+/// untouched.
+///
+/// [`Canvas`]: crate::terminal::Canvas
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{ColorMap, Pencil, Sprite};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, Color, VisualElement};
+/// #
+/// let mut ship = Sprite::from_text(" /\\ \n/##\\", Some(' '));
+/// ship.apply_color_map(&ColorMap::parse("# = yellow\n/ = grey\n\\ = grey").unwrap());
+/// assert_eq!(ship.dimension(), Vec2::xy(4, 2));
+///
+/// let mut canvas = Canvas::new(Vec2::xy(4, 2), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_sprite(&ship, Vec2::zero());
+/// assert_eq!(canvas.elem(Vec2::xy(1, 1)).unwrap().foreground, Color::Yellow);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sprite {
+    dimension: Vec2,
+    cells: Vec<Option<SpriteCell>>,
+}
+
+impl Sprite {
+    /// Creates a fully transparent [`Sprite`] with the given `dimension`.
+    pub fn new(dimension: Vec2) -> Sprite {
+        Sprite {
+            dimension,
+            cells: vec![None; (dimension.x.max(0) * dimension.y.max(0)) as usize],
+        }
+    }
+
+    /// Creates a [`Sprite`] from the lines of `text`. Its width is the one of the longest line.
+    ///
+    /// Cells with the `transparent` character, as well as the cells past the end of shorter
+    /// lines, are transparent.
+    pub fn from_text(text: &str, transparent: Option<char>) -> Sprite {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut sprite = Sprite::new(Vec2::xy(width, lines.len()));
+        for (y, line) in lines.iter().enumerate() {
+            for (x, value) in line.chars().enumerate() {
+                if Some(value) != transparent {
+                    sprite.set_cell(Vec2::xy(x, y), Some(SpriteCell::new(value)));
+                }
+            }
+        }
+        sprite
+    }
+
+    /// Loads a [`Sprite`] from the text file at the given `path`. See [`Sprite::from_text`].
+    pub fn load(path: impl AsRef<Path>, transparent: Option<char>) -> io::Result<Sprite> {
+        Ok(Sprite::from_text(&fs::read_to_string(path)?, transparent))
+    }
+
+    pub fn dimension(&self) -> Vec2 {
+        self.dimension
+    }
+
+    fn index(&self, pos: Vec2) -> Option<usize> {
+        let inside =
+            0 <= pos.x && 0 <= pos.y && pos.x < self.dimension.x && pos.y < self.dimension.y;
+        if inside {
+            Some((pos.y * self.dimension.x + pos.x) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at the given `pos`, or [`None`] if it is transparent or outside of the
+    /// [`Sprite`].
+    pub fn cell(&self, pos: Vec2) -> Option<&SpriteCell> {
+        self.index(pos).and_then(|index| self.cells[index].as_ref())
+    }
+
+    /// Sets the cell at the given `pos`. A [`None`] `cell` makes it transparent. Positions
+    /// outside of the [`Sprite`] are ignored.
+    pub fn set_cell(&mut self, pos: Vec2, cell: Option<SpriteCell>) -> &mut Sprite {
+        if let Some(index) = self.index(pos) {
+            self.cells[index] = cell;
+        }
+        self
+    }
+
+    /// Sets the colors and style of every cell whose character is in the given `color_map`.
+    pub fn apply_color_map(&mut self, color_map: &ColorMap) -> &mut Sprite {
+        for cell in self.cells.iter_mut().flatten() {
+            if let Some(entry) = color_map.get(cell.value) {
+                cell.foreground = entry.foreground.or(cell.foreground);
+                cell.background = entry.background.or(cell.background);
+                cell.style = entry.style.or(cell.style);
+            }
+        }
+        self
+    }
+
+    /// Mirrors the [`Sprite`] left to right. Characters with a mirrored counterpart, such as
+    /// `/` and `\` or `┌` and `┐`, are swapped.
+    pub fn flip_horizontal(&mut self) -> &mut Sprite {
+        let width = self.dimension.x.max(1) as usize;
+        for row in self.cells.chunks_mut(width) {
+            row.reverse();
+        }
+        self.map_values(transform::mirror_horizontal)
+    }
+
+    /// Mirrors the [`Sprite`] top to bottom. Characters with a mirrored counterpart, such as
+    /// `▀` and `▄` or `┌` and `└`, are swapped.
+    pub fn flip_vertical(&mut self) -> &mut Sprite {
+        let width = self.dimension.x.max(1) as usize;
+        let rows: Vec<Vec<Option<SpriteCell>>> = self
+            .cells
+            .chunks(width)
+            .rev()
+            .map(|row| row.to_vec())
+            .collect();
+        self.cells = rows.concat();
+        self.map_values(transform::mirror_vertical)
+    }
+
+    /// Returns a copy of the [`Sprite`] rotated clockwise by the given [`Rotation`]. Characters
+    /// with a rotated counterpart, such as `─` and `│` or `▀` and `▐`, are replaced.
+    pub fn rotate(&self, rotation: Rotation) -> Sprite {
+        let dimension = rotation.rotate_dimension(self.dimension);
+        let mut rotated = Sprite::new(dimension);
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let pos = Vec2::xy(x, y);
+                let cell = self
+                    .cell(rotation.source(pos, self.dimension))
+                    .map(|cell| SpriteCell {
+                        value: rotation.rotate_char(cell.value),
+                        ..*cell
+                    });
+                rotated.set_cell(pos, cell);
+            }
+        }
+        rotated
+    }
+
+    /// Returns a copy of the [`Sprite`] scaled by the given integer `factor` in each axis, where
+    /// each cell is repeated `factor.x` times horizontally and `factor.y` times vertically.
+    /// Factors smaller than 1 are taken as 1.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::Sprite;
+    /// # use ruscii::spatial::Vec2;
+    /// #
+    /// let sprite = Sprite::from_text("ab", None).scale(Vec2::xy(2, 2));
+    /// assert_eq!(sprite.dimension(), Vec2::xy(4, 2));
+    /// assert_eq!(sprite.cell(Vec2::xy(1, 1)).unwrap().value, 'a');
+    /// assert_eq!(sprite.cell(Vec2::xy(2, 0)).unwrap().value, 'b');
+    /// ```
+    pub fn scale(&self, factor: Vec2) -> Sprite {
+        let factor = Vec2::xy(factor.x.max(1), factor.y.max(1));
+        let dimension = self.dimension * factor;
+        let mut scaled = Sprite::new(dimension);
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let pos = Vec2::xy(x, y);
+                scaled.set_cell(pos, self.cell(pos / factor).copied());
+            }
+        }
+        scaled
+    }
+
+    fn map_values(&mut self, f: fn(char) -> char) -> &mut Sprite {
+        for cell in self.cells.iter_mut().flatten() {
+            cell.value = f(cell.value);
+        }
+        self
+    }
+}
+
+/// A mapping from characters to the colors and [`Style`] used to draw them in a [`Sprite`].
+///
+/// A color map is written as one entry per line: a character, an `=` and a space-separated list
+/// of options with the same syntax as the tags of [`StyledText`](super::StyledText):
+///
+/// ```text
+/// # = yellow bg:red
+/// o = 208 bold
+/// ```
+///
+/// The character is the first one of the line, so a line starting with a space maps the space,
+/// for example to give a solid background to a [`Sprite`] without transparency. Lines with only
+/// whitespace are ignored.
+///
+/// ```rust
+/// # use ruscii::drawing::{ColorMap, Sprite};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::Color;
+/// #
+/// let mut sprite = Sprite::from_text("# #", None);
+/// sprite.apply_color_map(&ColorMap::parse("  = bg:blue\n# = yellow").unwrap());
+/// assert_eq!(sprite.cell(Vec2::x(1)).unwrap().background, Some(Color::Blue));
+/// assert_eq!(sprite.cell(Vec2::x(0)).unwrap().foreground, Some(Color::Yellow));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColorMap {
+    entries: HashMap<char, Span>,
+}
+
+impl ColorMap {
+    /// Parses a [`ColorMap`] from the given `data`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`ColorMapError`] with the number of the first line, counting from 1, that is
+    /// not a valid entry.
+    pub fn parse(data: &str) -> Result<ColorMap, ColorMapError> {
+        let mut color_map = ColorMap::default();
+        for (number, line) in data.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let invalid = || ColorMapError::InvalidLine(number + 1);
+            let mut chars = line.chars();
+            let value = chars.next().ok_or_else(invalid)?;
+            let options = chars
+                .as_str()
+                .trim_start()
+                .strip_prefix('=')
+                .ok_or_else(invalid)?;
+
+            let mut entry = Span::new("");
+            for option in options.split_whitespace() {
+                apply_option(&mut entry, option).map_err(|_| invalid())?;
+            }
+            color_map.entries.insert(value, entry);
+        }
+        Ok(color_map)
+    }
+
+    /// Loads a [`ColorMap`] from the file at the given `path`. See [`ColorMap::parse`].
+    pub fn load(path: impl AsRef<Path>) -> Result<ColorMap, ColorMapError> {
+        ColorMap::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Sets the foreground [`Color`] for the given character.
+    pub fn insert(&mut self, value: char, color: Color) -> &mut ColorMap {
+        self.entries.insert(value, Span::new("").foreground(color));
+        self
+    }
+
+    fn get(&self, value: char) -> Option<&Span> {
+        self.entries.get(&value)
+    }
+}
+
+/// The reason why a [`ColorMap`] could not be loaded.
+#[derive(Debug)]
+pub enum ColorMapError {
+    Io(io::Error),
+    InvalidLine(usize),
+}
+
+impl Display for ColorMapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorMapError::Io(error) => write!(f, "cannot read the color map: {}", error),
+            ColorMapError::InvalidLine(line) => {
+                write!(f, "line {} is not a valid color map entry", line)
+            }
+        }
+    }
+}
+
+impl Error for ColorMapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ColorMapError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ColorMapError {
+    fn from(error: io::Error) -> Self {
+        ColorMapError::Io(error)
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws a [`Sprite`] with its top-left corner at the given `position`. Transparent cells are
+    /// skipped and the options not set in a cell are taken from the [`Pencil`].
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_sprite(&mut self, sprite: &Sprite, position: Vec2) -> &mut Pencil<'a> {
+        let (foreground, background, style) = (self.foreground, self.background, self.style);
+        for y in 0..sprite.dimension().y {
+            for x in 0..sprite.dimension().x {
+                if let Some(cell) = sprite.cell(Vec2::xy(x, y)) {
+                    self.foreground = cell.foreground.unwrap_or(foreground);
+                    self.background = cell.background.unwrap_or(background);
+                    self.style = cell.style.unwrap_or(style);
+                    self.draw_element(self.origin + position + Vec2::xy(x, y), cell.value);
+                }
+            }
+        }
+        self.foreground = foreground;
+        self.background = background;
+        self.style = style;
+        self
+    }
+}
@@ -0,0 +1,306 @@
+//! Text composed of spans with their own colors and style.
+
+use super::Pencil;
+use crate::spatial::Vec2;
+use crate::terminal::{Color, Style};
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A piece of text drawn with the same colors and [`Style`].
+///
+/// Options set to [`None`] are inherited from the [`Pencil`] that draws the span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub style: Option<Style>,
+}
+
+impl Span {
+    /// Creates a [`Span`] that inherits all its options from the [`Pencil`].
+    pub fn new(text: impl Into<String>) -> Span {
+        Span {
+            text: text.into(),
+            foreground: None,
+            background: None,
+            style: None,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one with the given foreground [`Color`].
+    pub fn foreground(mut self, color: Color) -> Span {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with the given background [`Color`].
+    pub fn background(mut self, color: Color) -> Span {
+        self.background = Some(color);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with the given [`Style`].
+    pub fn style(mut self, style: Style) -> Span {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// A text made of [`Span`]s, each one with its own colors and [`Style`].
+///
+/// A [`StyledText`] can be built span by span or parsed from a small markup language where tags
+/// between square brackets change the options of the text that follows them until the matching
+/// `[/]`. Tags can be nested and each tag can contain several space-separated options:
+///
+/// - A [`Color`] name or Xterm number, such as `red` or `36`, sets the foreground.
+/// - `bg:` followed by a [`Color`], such as `bg:blue`, sets the background.
+/// - `bold` and `plain` set the [`Style`].
+///
+/// A literal `[` is written as `[[`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{Pencil, StyledText};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, Color, VisualElement};
+/// #
+/// let text = StyledText::parse("[red]Game[/] [yellow bg:blue]Over[/]").unwrap();
+/// assert_eq!(text.width(), 9);
+///
+/// let mut canvas = Canvas::new(Vec2::xy(10, 1), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_styled_text(&text, Vec2::zero());
+/// assert_eq!(canvas.elem(Vec2::x(0)).unwrap().foreground, Color::Red);
+/// assert_eq!(canvas.elem(Vec2::x(5)).unwrap().background, Color::Blue);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyledText {
+    spans: Vec<Span>,
+}
+
+impl StyledText {
+    /// Parses a [`StyledText`] from the given `markup`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`MarkupError`] if a tag is not closed, contains an unknown option, or if a
+    /// `[/]` has no matching opening tag.
+    pub fn parse(markup: &str) -> Result<StyledText, MarkupError> {
+        let mut text = StyledText::default();
+        let mut stack: Vec<Span> = vec![Span::new("")];
+        let mut current = String::new();
+        let mut chars = markup.char_indices().peekable();
+
+        while let Some((index, value)) = chars.next() {
+            if value != '[' {
+                current.push(value);
+                continue;
+            }
+            if let Some((_, '[')) = chars.peek() {
+                chars.next();
+                current.push('[');
+                continue;
+            }
+
+            let mut tag = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, ']')) => break,
+                    Some((_, value)) => tag.push(value),
+                    None => return Err(MarkupError::new(index, MarkupErrorKind::UnclosedTag)),
+                }
+            }
+
+            let top = stack.last().unwrap();
+            text.push(Span {
+                text: std::mem::take(&mut current),
+                ..top.clone()
+            });
+
+            if tag.trim() == "/" {
+                if stack.len() == 1 {
+                    return Err(MarkupError::new(index, MarkupErrorKind::UnmatchedClose));
+                }
+                stack.pop();
+            } else {
+                let mut span = top.clone();
+                for option in tag.split_whitespace() {
+                    apply_option(&mut span, option)
+                        .map_err(|kind| MarkupError::new(index, kind))?;
+                }
+                stack.push(span);
+            }
+        }
+
+        text.push(Span {
+            text: current,
+            ..stack.pop().unwrap()
+        });
+        Ok(text)
+    }
+
+    /// Appends a [`Span`] to the end of the text. Empty spans are ignored.
+    pub fn push(&mut self, span: Span) -> &mut StyledText {
+        if !span.text.is_empty() {
+            self.spans.push(span);
+        }
+        self
+    }
+
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Returns the text without any style.
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Returns the number of cells taken by the widest line of the text.
+    pub fn width(&self) -> usize {
+        self.plain_text()
+            .split('\n')
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of lines of the text.
+    pub fn height(&self) -> usize {
+        self.plain_text().split('\n').count()
+    }
+
+    /// Returns the width and the height of the text.
+    pub fn dimension(&self) -> Vec2 {
+        Vec2::xy(self.width(), self.height())
+    }
+}
+
+impl From<Span> for StyledText {
+    fn from(span: Span) -> Self {
+        let mut text = StyledText::default();
+        text.push(span);
+        text
+    }
+}
+
+impl From<&str> for StyledText {
+    /// Creates a [`StyledText`] with a single unstyled span. No markup is parsed.
+    fn from(text: &str) -> Self {
+        StyledText::from(Span::new(text))
+    }
+}
+
+impl FromStr for StyledText {
+    type Err = MarkupError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StyledText::parse(s)
+    }
+}
+
+/// Applies a single markup option to the given `span`.
+pub(super) fn apply_option(span: &mut Span, option: &str) -> Result<(), MarkupErrorKind> {
+    let unknown = || MarkupErrorKind::UnknownOption(option.to_string());
+    match option.to_ascii_lowercase().as_str() {
+        "bold" => span.style = Some(Style::Bold),
+        "plain" => span.style = Some(Style::Plain),
+        option => match option.strip_prefix("bg:") {
+            Some(color) => span.background = Some(color.parse().map_err(|_| unknown())?),
+            None => {
+                let color = option.strip_prefix("fg:").unwrap_or(option);
+                span.foreground = Some(color.parse().map_err(|_| unknown())?);
+            }
+        },
+    }
+    Ok(())
+}
+
+/// The reason why a markup string could not be parsed into a [`StyledText`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkupErrorKind {
+    UnclosedTag,
+    UnmatchedClose,
+    UnknownOption(String),
+}
+
+#[derive(Debug)]
+pub struct MarkupError {
+    position: usize,
+    kind: MarkupErrorKind,
+}
+
+impl MarkupError {
+    fn new(position: usize, kind: MarkupErrorKind) -> MarkupError {
+        MarkupError { position, kind }
+    }
+
+    /// Returns the byte offset of the tag that caused the error.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn kind(&self) -> &MarkupErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for MarkupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            MarkupErrorKind::UnclosedTag => write!(f, "tag at {} is not closed", self.position),
+            MarkupErrorKind::UnmatchedClose => {
+                write!(f, "closing tag at {} has no opening tag", self.position)
+            }
+            MarkupErrorKind::UnknownOption(option) => {
+                write!(f, "unknown option '{}' in tag at {}", option, self.position)
+            }
+        }
+    }
+}
+
+impl Error for MarkupError {}
+
+impl<'a> Pencil<'a> {
+    /// Draws a [`StyledText`] at the given `position`. Each span is drawn with its own options,
+    /// falling back to the ones of the [`Pencil`] for those not set.
+    ///
+    /// A `'\n'` in the text continues drawing on the next line, below the given `position`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_styled_text(&mut self, text: &StyledText, position: Vec2) -> &mut Pencil<'a> {
+        let (foreground, background, style) = (self.foreground, self.background, self.style);
+        let mut cursor = position;
+        for span in text.spans() {
+            self.foreground = span.foreground.unwrap_or(foreground);
+            self.background = span.background.unwrap_or(background);
+            self.style = span.style.unwrap_or(style);
+            for value in span.text.chars() {
+                if value == '\n' {
+                    cursor = Vec2::xy(position.x, cursor.y + 1);
+                } else {
+                    self.draw_element(self.origin + cursor, value);
+                    cursor.x += 1;
+                }
+            }
+        }
+        self.foreground = foreground;
+        self.background = background;
+        self.style = style;
+        self
+    }
+
+    /// Draws a [`StyledText`] centered at the given `position`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_center_styled_text(
+        &mut self,
+        text: &StyledText,
+        position: Vec2,
+    ) -> &mut Pencil<'a> {
+        self.draw_styled_text(text, position - Vec2::x(text.width() / 2))
+    }
+}
@@ -0,0 +1,481 @@
+//! Tables with borders, column sizing and alignment.
+
+use super::junction;
+use super::{HAlign, Pencil, RectCharset};
+use crate::spatial::Vec2;
+use crate::terminal::Color;
+
+/// The set of all characters needed to draw the borders and the inner separators of a
+/// [`Table`].
+///
+/// It is usually built from a [`RectCharset`], from which the inner lines and their junctions are
+/// derived:
+///
+/// ```rust
+/// # use ruscii::drawing::{RectCharset, TableCharset};
+/// #
+/// let charset = TableCharset::from(&RectCharset::double_lines());
+/// assert_eq!(charset.cross, '╬');
+/// assert_eq!(charset.top_junction, '╦');
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableCharset {
+    pub border: RectCharset,
+    pub horizontal: char,
+    pub vertical: char,
+    pub top_junction: char,
+    pub bottom_junction: char,
+    pub left_junction: char,
+    pub right_junction: char,
+    pub cross: char,
+}
+
+impl TableCharset {
+    /// Returns a [`TableCharset`] for a single-line table.
+    ///
+    /// # Table
+    ///
+    /// Using this charset will provide a table that looks like this:
+    ///
+    /// ```text
+    /// ┌──────┬─────┐
+    /// │ruscii│0.4.0│
+    /// ├──────┼─────┤
+    /// │tables│yes  │
+    /// └──────┴─────┘
+    /// ```
+    pub fn simple_lines() -> TableCharset {
+        TableCharset::from(&RectCharset::simple_lines())
+    }
+
+    /// Returns a [`TableCharset`] for a single-line rounded-corner table.
+    pub fn simple_round_lines() -> TableCharset {
+        TableCharset::from(&RectCharset::simple_round_lines())
+    }
+
+    /// Returns a [`TableCharset`] for a double-line table.
+    pub fn double_lines() -> TableCharset {
+        TableCharset::from(&RectCharset::double_lines())
+    }
+}
+
+impl From<&RectCharset> for TableCharset {
+    /// Generates a [`TableCharset`] whose inner lines are the top and left edges of the given
+    /// `border`. Junctions are the box-drawing characters that join those lines, or the
+    /// top-left corner of the `border` if it is not made of box-drawing characters.
+    fn from(border: &RectCharset) -> Self {
+        let (horizontal, vertical) = (border.top, border.left);
+        let junction = |up: bool, right: bool, down: bool, left: bool| {
+            let glyph = match (junction::arms(horizontal), junction::arms(vertical)) {
+                (Some(h), Some(v)) => junction::glyph(
+                    [
+                        if up { v[0] } else { 0 },
+                        if right { h[1] } else { 0 },
+                        if down { v[2] } else { 0 },
+                        if left { h[3] } else { 0 },
+                    ],
+                    false,
+                ),
+                _ => None,
+            };
+            glyph.unwrap_or(border.top_left)
+        };
+        TableCharset {
+            horizontal,
+            vertical,
+            top_junction: junction(false, true, true, true),
+            bottom_junction: junction(true, true, false, true),
+            left_junction: junction(true, true, true, false),
+            right_junction: junction(true, false, true, true),
+            cross: junction(true, true, true, true),
+            border: border.clone(),
+        }
+    }
+}
+
+/// The way the width of a column of a [`Table`] is computed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ColumnWidth {
+    /// As wide as the widest cell of the column, including its header.
+    Auto,
+    /// The given number of characters.
+    Fixed(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Column {
+    width: ColumnWidth,
+    align: HAlign,
+}
+
+/// A grid of text cells with headers, drawn with [`Pencil::draw_table`].
+///
+/// Columns are as wide as their content, or have a fixed width, and are shrunk to fit the
+/// rectangle where the table is drawn, starting with the widest automatic ones, down to one
+/// character. If the table is still too wide, it is cut off at the right edge of the rectangle
+/// instead of wrapping. Cells that do not fit in their column are truncated with an ellipsis.
+/// Rows that do not fit in the rectangle are not drawn.
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{ColumnWidth, HAlign, Pencil, Table};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, Color, VisualElement};
+/// #
+/// let mut table = Table::new(&["Player", "Score"])
+///     .column_align(1, HAlign::Right)
+///     .column_width(0, ColumnWidth::Fixed(6))
+///     .highlighted_rows(&[1]);
+/// table.push_row(&["Alice", "120"]).push_row(&["Bartholomew", "95"]);
+///
+/// let mut canvas = Canvas::new(Vec2::xy(20, 6), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_table(&table, Vec2::zero(), Vec2::xy(20, 6));
+/// let row = |y| (0..18).map(|x| canvas.elem(Vec2::xy(x, y)).unwrap().value).collect::<String>();
+/// assert_eq!(row(0), "┌────────┬───────┐");
+/// assert_eq!(row(1), "│ Player │ Score │");
+/// assert_eq!(row(2), "├────────┼───────┤");
+/// assert_eq!(row(3), "│ Alice  │   120 │");
+/// assert_eq!(row(4), "│ Barth… │    95 │");
+/// assert_eq!(row(5), "└────────┴───────┘");
+/// assert_eq!(canvas.elem(Vec2::xy(2, 4)).unwrap().background, Color::White);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    columns: Vec<Column>,
+    rows: Vec<Vec<String>>,
+    charset: TableCharset,
+    padding: usize,
+    highlighted: Vec<usize>,
+    highlight: Option<(Color, Color)>,
+}
+
+impl Table {
+    /// Creates a [`Table`] with a column for each of the given `headers`, drawn with
+    /// [`TableCharset::simple_lines`]. A table without headers has no header row.
+    pub fn new(headers: &[&str]) -> Table {
+        Table {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            charset: TableCharset::simple_lines(),
+            padding: 1,
+            highlighted: Vec::new(),
+            highlight: None,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one drawn with the given [`TableCharset`].
+    pub fn charset(mut self, charset: TableCharset) -> Table {
+        self.charset = charset;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with `padding` spaces on both sides of each
+    /// cell.
+    pub fn padding(mut self, padding: usize) -> Table {
+        self.padding = padding;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one where the column with the given `index` has
+    /// the given [`ColumnWidth`].
+    pub fn column_width(mut self, index: usize, width: ColumnWidth) -> Table {
+        self.column_mut(index).width = width;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one where the cells of the column with the given
+    /// `index`, including its header, have the given alignment. [`HAlign::Justify`] is taken as
+    /// [`HAlign::Left`].
+    pub fn column_align(mut self, index: usize, align: HAlign) -> Table {
+        self.column_mut(index).align = align;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one where the rows with the given indices,
+    /// counting from the first row after the headers, are highlighted.
+    pub fn highlighted_rows(mut self, rows: &[usize]) -> Table {
+        self.highlighted = rows.to_vec();
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that draws the highlighted rows with the given
+    /// colors. By default, highlighted rows swap the foreground and background colors of the
+    /// [`Pencil`].
+    pub fn highlight(mut self, foreground: Color, background: Color) -> Table {
+        self.highlight = Some((foreground, background));
+        self
+    }
+
+    /// Adds a row with the given `cells`. Missing cells are empty.
+    pub fn push_row(&mut self, cells: &[&str]) -> &mut Table {
+        self.rows
+            .push(cells.iter().map(|cell| cell.to_string()).collect());
+        self
+    }
+
+    /// Removes all the rows, keeping the headers and the configuration of the columns.
+    pub fn clear_rows(&mut self) -> &mut Table {
+        self.rows.clear();
+        self
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Returns the number of columns: the largest number of headers or cells in a row.
+    pub fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.len())
+            .chain(Some(self.headers.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn column_mut(&mut self, index: usize) -> &mut Column {
+        if self.columns.len() <= index {
+            self.columns.resize(
+                index + 1,
+                Column {
+                    width: ColumnWidth::Auto,
+                    align: HAlign::Left,
+                },
+            );
+        }
+        &mut self.columns[index]
+    }
+
+    fn align(&self, column: usize) -> HAlign {
+        self.columns
+            .get(column)
+            .map_or(HAlign::Left, |column| column.align)
+    }
+
+    /// Returns the width of the content of each column, shrunk to fit in `max_width` characters
+    /// if possible.
+    fn widths(&self, max_width: usize) -> Vec<usize> {
+        let count = self.column_count();
+        let cell_width = |row: &Vec<String>, i: usize| row.get(i).map_or(0, |c| c.chars().count());
+        let mut widths: Vec<(usize, bool)> = (0..count)
+            .map(|i| match self.columns.get(i).map(|column| column.width) {
+                Some(ColumnWidth::Fixed(width)) => (width, true),
+                _ => {
+                    let content = self.rows.iter().map(|row| cell_width(row, i));
+                    (
+                        content
+                            .chain(Some(cell_width(&self.headers, i)))
+                            .max()
+                            .unwrap(),
+                        false,
+                    )
+                }
+            })
+            .collect();
+
+        let decorations = count * 2 * self.padding + count + 1;
+        let mut total: usize = widths.iter().map(|(width, _)| width).sum::<usize>() + decorations;
+        while total > max_width {
+            let widest = widths
+                .iter_mut()
+                .filter(|(width, _)| *width > 1)
+                .max_by_key(|(width, fixed)| (!*fixed, *width));
+            match widest {
+                Some((width, _)) => *width -= 1,
+                None => break,
+            }
+            total -= 1;
+        }
+        widths.into_iter().map(|(width, _)| width).collect()
+    }
+}
+
+/// Returns `text` truncated with an ellipsis or padded with spaces to exactly `width` characters.
+fn fit(text: &str, width: usize, align: HAlign) -> String {
+    let len = text.chars().count();
+    if len > width {
+        let mut fitted: String = text.chars().take(width.saturating_sub(1)).collect();
+        fitted.extend(Some('…').filter(|_| width > 0));
+        return fitted;
+    }
+    let space = width - len;
+    let left = match align {
+        HAlign::Left | HAlign::Justify => 0,
+        HAlign::Center => space / 2,
+        HAlign::Right => space,
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(space - left))
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws a [`Table`] into the rectangle with the given `dimension` whose top-left corner is
+    /// at the given `position`. The table is as large as its content, up to the rectangle.
+    ///
+    /// Nothing is drawn past the right edge of the rectangle, even if the columns cannot shrink
+    /// enough to fit in it:
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::{Pencil, Table};
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let mut table = Table::new(&["a", "b", "c", "d"]);
+    /// table.push_row(&["1", "2", "3", "4"]);
+    ///
+    /// let mut canvas = Canvas::new(Vec2::xy(12, 5), &VisualElement::default());
+    /// Pencil::new(&mut canvas).draw_table(&table, Vec2::zero(), Vec2::xy(8, 5));
+    /// let row = |y| (0..12).map(|x| canvas.elem(Vec2::xy(x, y)).unwrap().value).collect::<String>();
+    /// assert_eq!(row(0), "┌───┬───    ");
+    /// assert_eq!(row(1), "│ a │ b     ");
+    /// ```
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_table(
+        &mut self,
+        table: &Table,
+        position: Vec2,
+        dimension: Vec2,
+    ) -> &mut Pencil<'a> {
+        let widths = table.widths(dimension.x.max(0) as usize);
+        if widths.is_empty() || dimension.y < 2 {
+            return self;
+        }
+        let charset = &table.charset;
+        let padding = table.padding;
+        let edge = position.x + dimension.x;
+
+        let header_rows = if table.headers.is_empty() { 0 } else { 2 };
+        let visible_rows = (dimension.y as usize - 2)
+            .saturating_sub(header_rows)
+            .min(table.rows.len());
+
+        let mut y = position.y;
+        self.draw_separator(
+            &widths,
+            padding,
+            Vec2::xy(position.x, y),
+            edge,
+            [
+                charset.border.top_left,
+                charset.border.top,
+                charset.top_junction,
+                charset.border.top_right,
+            ],
+        );
+        y += 1;
+        if !table.headers.is_empty() && header_rows as i32 <= dimension.y - 2 {
+            self.draw_table_row(
+                table,
+                &table.headers,
+                &widths,
+                Vec2::xy(position.x, y),
+                edge,
+                false,
+            );
+            self.draw_separator(
+                &widths,
+                padding,
+                Vec2::xy(position.x, y + 1),
+                edge,
+                [
+                    charset.left_junction,
+                    charset.horizontal,
+                    charset.cross,
+                    charset.right_junction,
+                ],
+            );
+            y += 2;
+        }
+        for (index, row) in table.rows.iter().take(visible_rows).enumerate() {
+            let highlighted = table.highlighted.contains(&index);
+            let position = Vec2::xy(position.x, y);
+            self.draw_table_row(table, row, &widths, position, edge, highlighted);
+            y += 1;
+        }
+        self.draw_separator(
+            &widths,
+            padding,
+            Vec2::xy(position.x, y),
+            edge,
+            [
+                charset.border.bottom_left,
+                charset.border.bottom,
+                charset.bottom_junction,
+                charset.border.bottom_right,
+            ],
+        )
+    }
+
+    /// Draws `text` from the given `position`, skipping the characters at or past the column
+    /// `edge`.
+    fn draw_clipped_text(&mut self, text: &str, position: Vec2, edge: i32) {
+        for (i, value) in text.chars().enumerate() {
+            let position = position + Vec2::x(i);
+            if position.x >= edge {
+                break;
+            }
+            self.draw_char(value, position);
+        }
+    }
+
+    /// Draws a horizontal line of a table with the given `[left, line, junction, right]`
+    /// characters, up to the column `edge`.
+    fn draw_separator(
+        &mut self,
+        widths: &[usize],
+        padding: usize,
+        position: Vec2,
+        edge: i32,
+        [left, line, junction, right]: [char; 4],
+    ) -> &mut Pencil<'a> {
+        let mut text = String::from(left);
+        for (i, width) in widths.iter().enumerate() {
+            text.extend(std::iter::repeat_n(line, width + 2 * padding));
+            text.push(if i + 1 == widths.len() {
+                right
+            } else {
+                junction
+            });
+        }
+        self.draw_clipped_text(&text, position, edge);
+        self
+    }
+
+    fn draw_table_row(
+        &mut self,
+        table: &Table,
+        cells: &[String],
+        widths: &[usize],
+        position: Vec2,
+        edge: i32,
+        highlighted: bool,
+    ) -> &mut Pencil<'a> {
+        let (foreground, background) = (self.foreground, self.background);
+        let padding = " ".repeat(table.padding);
+        let mut x = position.x;
+        let border = table.charset.border.left.to_string();
+        self.draw_clipped_text(&border, Vec2::xy(x, position.y), edge);
+        for (i, &width) in widths.iter().enumerate() {
+            let cell = cells.get(i).map_or("", |cell| cell.as_str());
+            let text = format!("{}{}{}", padding, fit(cell, width, table.align(i)), padding);
+            if highlighted {
+                let (fg, bg) = table.highlight.unwrap_or((background, foreground));
+                self.foreground = fg;
+                self.background = bg;
+            }
+            self.draw_clipped_text(&text, Vec2::xy(x + 1, position.y), edge);
+            self.foreground = foreground;
+            self.background = background;
+            x += text.chars().count() as i32 + 1;
+            let separator = match i + 1 == widths.len() {
+                true => table.charset.border.right,
+                false => table.charset.vertical,
+            };
+            self.draw_clipped_text(&separator.to_string(), Vec2::xy(x, position.y), edge);
+        }
+        self
+    }
+}
@@ -0,0 +1,331 @@
+//! Layout of multi-line text blocks inside a rectangle.
+
+use super::Pencil;
+use crate::spatial::Vec2;
+
+/// The horizontal alignment of each line of a text block.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HAlign {
+    Left,
+    Center,
+    Right,
+    /// Spreads the words of every line across the whole width, except for the last line of each
+    /// paragraph, which is left-aligned.
+    Justify,
+}
+
+/// The vertical alignment of a text block inside its rectangle.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VAlign {
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// The options used by [`Pencil::draw_text_box`] to lay out a string inside a rectangle.
+///
+/// The text is split into paragraphs at each `'\n'`, tabs are expanded to the next tab stop and,
+/// if wrapping is enabled, each paragraph is word-wrapped to the width of the rectangle. Words
+/// longer than the width are broken. Lines that do not fit in the rectangle are discarded and,
+/// if an ellipsis is set, the last visible line ends with it.
+///
+/// [`TextLayout::default`] is left- and top-aligned, wraps, uses a tab width of 4 and has no
+/// ellipsis.
+///
+/// ## Example
+///
+/// ```rust
+/// # use ruscii::drawing::{HAlign, Pencil, TextLayout, VAlign};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let mut canvas = Canvas::new(Vec2::xy(10, 3), &VisualElement::default());
+/// let layout = TextLayout::default()
+///     .align(HAlign::Center)
+///     .vertical_align(VAlign::Middle)
+///     .ellipsis("…");
+///
+/// let lines = Pencil::new(&mut canvas).draw_text_box(
+///     "Game over\nPress any key",
+///     Vec2::zero(),
+///     Vec2::xy(10, 3),
+///     &layout,
+/// );
+/// assert_eq!(lines, 3);
+/// assert_eq!(canvas.elem(Vec2::xy(0, 0)).unwrap().value, 'G');
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextLayout {
+    pub align: HAlign,
+    pub vertical_align: VAlign,
+    pub wrap: bool,
+    pub tab_width: usize,
+    pub ellipsis: Option<String>,
+}
+
+impl Default for TextLayout {
+    fn default() -> Self {
+        Self {
+            align: HAlign::Left,
+            vertical_align: VAlign::Top,
+            wrap: true,
+            tab_width: 4,
+            ellipsis: None,
+        }
+    }
+}
+
+impl TextLayout {
+    /// Consumes the receiver and returns a new one with the given horizontal alignment.
+    pub fn align(mut self, align: HAlign) -> TextLayout {
+        self.align = align;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with the given vertical alignment.
+    pub fn vertical_align(mut self, align: VAlign) -> TextLayout {
+        self.vertical_align = align;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that wraps words or not.
+    ///
+    /// When wrapping is disabled, each paragraph occupies one line and is cut at the right edge.
+    pub fn wrap(mut self, wrap: bool) -> TextLayout {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that expands tabs to multiples of `tab_width`.
+    pub fn tab_width(mut self, tab_width: usize) -> TextLayout {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that marks truncated text with `ellipsis`.
+    pub fn ellipsis(mut self, ellipsis: impl Into<String>) -> TextLayout {
+        self.ellipsis = Some(ellipsis.into());
+        self
+    }
+
+    /// Splits `text` into the lines it would occupy in a rectangle of the given `width`, before
+    /// any truncation or alignment.
+    ///
+    /// This is useful to measure a text block before drawing it.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::TextLayout;
+    /// #
+    /// let lines = TextLayout::default().lines("the quick brown fox", 10);
+    /// assert_eq!(lines, vec!["the quick", "brown fox"]);
+    /// ```
+    pub fn lines(&self, text: &str, width: usize) -> Vec<String> {
+        self.wrapped_lines(text, width)
+            .into_iter()
+            .map(|line| line.chars.into_iter().collect())
+            .collect()
+    }
+
+    fn wrapped_lines(&self, text: &str, width: usize) -> Vec<Line> {
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let paragraph = self.expand_tabs(paragraph.trim_end_matches('\r'));
+            let mut paragraph_lines = if self.wrap {
+                wrap_paragraph(&paragraph, width)
+            } else {
+                vec![Line {
+                    chars: paragraph,
+                    paragraph_end: false,
+                }]
+            };
+            if let Some(last) = paragraph_lines.last_mut() {
+                last.paragraph_end = true;
+            }
+            lines.append(&mut paragraph_lines);
+        }
+        lines
+    }
+
+    fn expand_tabs(&self, text: &str) -> Vec<char> {
+        let mut chars = Vec::new();
+        for value in text.chars() {
+            if value == '\t' {
+                let tab_width = self.tab_width.max(1);
+                let spaces = tab_width - chars.len() % tab_width;
+                chars.extend(std::iter::repeat_n(' ', spaces));
+            } else {
+                chars.push(value);
+            }
+        }
+        chars
+    }
+
+    /// Wraps, truncates and aligns `text` to fit into `dimension`, returning the indentation and
+    /// the characters of each line.
+    fn layout(&self, text: &str, dimension: Vec2) -> Vec<(usize, Vec<char>)> {
+        let width = dimension.x.max(0) as usize;
+        let height = dimension.y.max(0) as usize;
+
+        let mut lines = self.wrapped_lines(text, width);
+        let truncated = lines.len() > height;
+        lines.truncate(height);
+
+        let ellipsis = self
+            .ellipsis
+            .as_deref()
+            .map(|e| e.chars().collect::<Vec<_>>());
+        for (i, line) in lines.iter_mut().enumerate() {
+            let overflow = line.chars.len() > width;
+            let last_truncated = truncated && i + 1 == height;
+            if let Some(ellipsis) = &ellipsis {
+                if overflow || last_truncated {
+                    let keep = width.saturating_sub(ellipsis.len());
+                    line.chars.truncate(keep);
+                    while line.chars.last() == Some(&' ') {
+                        line.chars.pop();
+                    }
+                    line.chars.extend(ellipsis.iter().take(width));
+                    line.paragraph_end = true;
+                    continue;
+                }
+            }
+            line.chars.truncate(width);
+        }
+
+        lines
+            .into_iter()
+            .map(|line| self.align_line(line, width))
+            .collect()
+    }
+
+    fn align_line(&self, line: Line, width: usize) -> (usize, Vec<char>) {
+        let padding = width.saturating_sub(line.chars.len());
+        match self.align {
+            HAlign::Left => (0, line.chars),
+            HAlign::Center => (padding / 2, line.chars),
+            HAlign::Right => (padding, line.chars),
+            HAlign::Justify if line.paragraph_end => (0, line.chars),
+            HAlign::Justify => (0, justify(&line.chars, width)),
+        }
+    }
+}
+
+/// A laid out line and whether it closes its paragraph.
+struct Line {
+    chars: Vec<char>,
+    paragraph_end: bool,
+}
+
+/// Greedily wraps a paragraph at word boundaries.
+///
+/// Spaces inside a line are kept as they are, while spaces at a wrapping point are dropped.
+fn wrap_paragraph(paragraph: &[char], width: usize) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut line: Vec<char> = Vec::new();
+    let mut spaces = 0;
+    let mut continuation = false;
+
+    let push = |lines: &mut Vec<Line>, line: &mut Vec<char>| {
+        lines.push(Line {
+            chars: std::mem::take(line),
+            paragraph_end: false,
+        });
+    };
+
+    for word in paragraph.split_inclusive(|c| *c == ' ') {
+        let trailing = word.iter().rev().take_while(|c| **c == ' ').count();
+        let word = &word[..word.len() - trailing];
+        if word.is_empty() {
+            spaces += trailing;
+            continue;
+        }
+
+        let gap = if line.is_empty() && continuation {
+            0
+        } else {
+            spaces
+        };
+        if line.len() + gap + word.len() <= width {
+            line.extend(std::iter::repeat_n(' ', gap));
+            line.extend_from_slice(word);
+        } else {
+            if !line.is_empty() {
+                push(&mut lines, &mut line);
+                continuation = true;
+            }
+            let mut chunks = word.chunks(width.max(1)).peekable();
+            while let Some(chunk) = chunks.next() {
+                if chunks.peek().is_some() {
+                    line.extend_from_slice(chunk);
+                    push(&mut lines, &mut line);
+                    continuation = true;
+                } else {
+                    line.extend_from_slice(chunk);
+                }
+            }
+        }
+        spaces = trailing;
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        push(&mut lines, &mut line);
+    }
+    lines
+}
+
+/// Spreads the words of `chars` so that the line spans exactly `width` cells.
+fn justify(chars: &[char], width: usize) -> Vec<char> {
+    let words: Vec<&[char]> = chars
+        .split(|c| *c == ' ')
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.len() < 2 {
+        return chars.to_vec();
+    }
+
+    let letters: usize = words.iter().map(|w| w.len()).sum();
+    let gaps = words.len() - 1;
+    let spaces = width.saturating_sub(letters).max(gaps);
+
+    let mut justified = Vec::with_capacity(width);
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            let extra = if i <= spaces % gaps { 1 } else { 0 };
+            justified.extend(std::iter::repeat_n(' ', spaces / gaps + extra));
+        }
+        justified.extend_from_slice(word);
+    }
+    justified
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws `text` inside the rectangle with its top-left corner at `position` and the given
+    /// `dimension`, following the wrapping, alignment and truncation options of `layout`.
+    ///
+    /// Nothing is drawn outside the rectangle. Cells of the rectangle not covered by text are
+    /// left untouched.
+    ///
+    /// Returns the number of lines used.
+    pub fn draw_text_box(
+        &mut self,
+        text: &str,
+        position: Vec2,
+        dimension: Vec2,
+        layout: &TextLayout,
+    ) -> usize {
+        let lines = layout.layout(text, dimension);
+        let top = match layout.vertical_align {
+            VAlign::Top => 0,
+            VAlign::Middle => (dimension.y - lines.len() as i32) / 2,
+            VAlign::Bottom => dimension.y - lines.len() as i32,
+        };
+
+        for (y, (indent, line)) in lines.iter().enumerate() {
+            for (x, value) in line.iter().enumerate() {
+                let cell = position + Vec2::xy(indent + x, top + y as i32);
+                self.draw_element(self.origin + cell, *value);
+            }
+        }
+        lines.len()
+    }
+}
@@ -0,0 +1,217 @@
+//! Palettes of colors by role, shared by the widgets and the [`Pencil`].
+
+use super::Pencil;
+use crate::terminal::{Color, VisualElement};
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The colors of an interface by the role they play, instead of hard-coding them in each
+/// [`Pencil::set_foreground`] call.
+///
+/// [`Pencil::set_theme`] sets the colors of the [`Pencil`] to the `foreground` and `background`
+/// of a theme, and the widgets of the [`gui`](crate::gui) module take the other roles from the
+/// theme of the pencil that draws them. Since a [`Pencil`] is created each frame, switching the
+/// theme at runtime is a matter of passing another one.
+///
+/// Themes can be loaded from `key = value` files, a subset of TOML, so they can be tweaked
+/// without recompiling. See [`Theme::parse`].
+///
+/// ```rust
+/// # use ruscii::drawing::{Pencil, Theme};
+/// # use ruscii::spatial::Vec2;
+/// # use ruscii::terminal::{Canvas, Color, VisualElement};
+/// #
+/// let theme = Theme::parse("accent = \"magenta\"\nerror = \"#ff5f5f\"").unwrap();
+/// assert_eq!(theme.accent, Color::Magenta);
+///
+/// let mut canvas = Canvas::new(Vec2::xy(10, 1), &VisualElement::default());
+/// let mut pencil = Pencil::new(&mut canvas);
+/// pencil.set_theme(&theme);
+/// let error = pencil.theme().error;
+/// pencil.set_foreground(error).draw_text("Game over", Vec2::zero());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// The color of the text.
+    pub foreground: Color,
+    /// The color behind everything.
+    pub background: Color,
+    /// The color of the main elements, such as the filled part of progress bars.
+    pub primary: Color,
+    /// The color of the elements that stand out, such as titles.
+    pub accent: Color,
+    /// The color of borders and scrollbars.
+    pub border: Color,
+    /// The background of focused and selected elements.
+    pub selection: Color,
+    /// The text of focused and selected elements.
+    pub selection_foreground: Color,
+    /// The color of disabled elements and placeholders.
+    pub disabled: Color,
+    /// The color of errors.
+    pub error: Color,
+}
+
+impl Theme {
+    /// Returns the dark [`Theme`], white on black, which is the default.
+    pub fn dark() -> Theme {
+        Theme {
+            foreground: Color::White,
+            background: Color::Black,
+            primary: Color::Cyan,
+            accent: Color::Yellow,
+            border: Color::White,
+            selection: Color::White,
+            selection_foreground: Color::Black,
+            disabled: Color::DarkGrey,
+            error: Color::Red,
+        }
+    }
+
+    /// Returns a light [`Theme`], black on white.
+    pub fn light() -> Theme {
+        Theme {
+            foreground: Color::Black,
+            background: Color::White,
+            primary: Color::Blue,
+            accent: Color::Magenta,
+            border: Color::DarkGrey,
+            selection: Color::Blue,
+            selection_foreground: Color::White,
+            disabled: Color::Grey,
+            error: Color::Red,
+        }
+    }
+
+    /// Parses a [`Theme`] from lines of `role = color`, where the roles are the names of the
+    /// fields of [`Theme`] and the colors are parsed as in [`Color::from_str`], with or without
+    /// quotes. Roles not listed keep the color of [`Theme::dark`]. Empty lines, comments starting
+    /// with `#` and `[section]` headers are ignored.
+    ///
+    /// ```text
+    /// # Ocean palette
+    /// [colors]
+    /// background = "#001b2e"
+    /// primary = "cyan"
+    /// selection = 31
+    /// ```
+    ///
+    /// Returns a [`ThemeError`] with the number of the first line, counting from 1, that is not
+    /// a valid entry.
+    ///
+    /// [`Color::from_str`]: crate::terminal::Color::from_str
+    pub fn parse(data: &str) -> Result<Theme, ThemeError> {
+        let mut theme = Theme::dark();
+        for (number, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+            let invalid = || ThemeError::InvalidLine(number + 1);
+            let (role, value) = line.split_once('=').ok_or_else(invalid)?;
+            let value = value.trim().split(" #").next().unwrap_or_default().trim();
+            let value = value.trim_matches(|c| c == '"' || c == '\'');
+            let color = Color::from_str(value).map_err(|_| invalid())?;
+            *theme.role_mut(role.trim()).ok_or_else(invalid)? = color;
+        }
+        Ok(theme)
+    }
+
+    /// Loads a [`Theme`] from the file at the given `path`. See [`Theme::parse`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Theme, ThemeError> {
+        Theme::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Returns a [`VisualElement`] with the foreground and background of the theme, to be set as
+    /// the default element of the [`Canvas`](crate::terminal::Canvas) of the window so the whole
+    /// screen takes the background.
+    pub fn default_element(&self) -> VisualElement {
+        VisualElement {
+            foreground: self.foreground,
+            background: self.background,
+            ..VisualElement::default()
+        }
+    }
+
+    fn role_mut(&mut self, role: &str) -> Option<&mut Color> {
+        match role {
+            "foreground" => Some(&mut self.foreground),
+            "background" => Some(&mut self.background),
+            "primary" => Some(&mut self.primary),
+            "accent" => Some(&mut self.accent),
+            "border" => Some(&mut self.border),
+            "selection" => Some(&mut self.selection),
+            "selection_foreground" => Some(&mut self.selection_foreground),
+            "disabled" => Some(&mut self.disabled),
+            "error" => Some(&mut self.error),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ThemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Theme::parse(s)
+    }
+}
+
+/// The reason why a [`Theme`] could not be loaded.
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(io::Error),
+    InvalidLine(usize),
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(error) => write!(f, "cannot read the theme: {}", error),
+            ThemeError::InvalidLine(line) => write!(f, "line {} is not a valid theme entry", line),
+        }
+    }
+}
+
+impl Error for ThemeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ThemeError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ThemeError {
+    fn from(error: io::Error) -> Self {
+        ThemeError::Io(error)
+    }
+}
+
+impl<'a> Pencil<'a> {
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the [`Theme`] used by the widgets drawn with the [`Pencil`], and sets its foreground
+    /// and background colors to the ones of the theme.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn set_theme(&mut self, theme: &Theme) -> &mut Pencil<'a> {
+        self.theme = *theme;
+        self.foreground = theme.foreground;
+        self.background = theme.background;
+        self
+    }
+}
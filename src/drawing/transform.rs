@@ -0,0 +1,143 @@
+//! Glyph-aware transformations of characters, used to rotate and mirror canvases and sprites.
+
+use super::junction;
+use super::Pencil;
+use crate::spatial::Vec2;
+use crate::terminal::Canvas;
+
+/// Pairs of characters that are the mirror image of each other along a vertical axis.
+const HORIZONTAL_MIRRORS: [(char, char); 8] = [
+    ('/', '\\'),
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('<', '>'),
+    ('▌', '▐'),
+    ('◀', '▶'),
+    ('«', '»'),
+];
+
+/// Pairs of characters that are the mirror image of each other along a horizontal axis.
+const VERTICAL_MIRRORS: [(char, char); 4] = [('/', '\\'), ('▀', '▄'), ('▲', '▼'), ('\'', ',')];
+
+fn swap(value: char, pairs: &[(char, char)]) -> Option<char> {
+    pairs.iter().find_map(|&(a, b)| {
+        if value == a {
+            Some(b)
+        } else if value == b {
+            Some(a)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the character that looks like `value` mirrored left to right.
+pub(crate) fn mirror_horizontal(value: char) -> char {
+    swap(value, &HORIZONTAL_MIRRORS)
+        .or_else(|| {
+            let [up, right, down, left] = junction::arms(value)?;
+            junction::glyph([up, left, down, right], junction::is_rounded(value))
+        })
+        .unwrap_or(value)
+}
+
+/// Returns the character that looks like `value` mirrored top to bottom.
+pub(crate) fn mirror_vertical(value: char) -> char {
+    swap(value, &VERTICAL_MIRRORS)
+        .or_else(|| {
+            let [up, right, down, left] = junction::arms(value)?;
+            junction::glyph([down, right, up, left], junction::is_rounded(value))
+        })
+        .unwrap_or(value)
+}
+
+/// Cycles of characters that turn into the next one when rotated a quarter turn clockwise.
+const CLOCKWISE_TURNS: [[char; 4]; 4] = [
+    ['▀', '▐', '▄', '▌'],
+    ['▲', '▶', '▼', '◀'],
+    ['↑', '→', '↓', '←'],
+    ['/', '\\', '/', '\\'],
+];
+
+/// Pairs of characters that turn into each other when rotated a quarter turn.
+const QUARTER_TURNS: [(char, char); 1] = [('-', '|')];
+
+/// Returns the character that looks like `value` rotated a quarter turn clockwise.
+pub(crate) fn rotate_clockwise(value: char) -> char {
+    CLOCKWISE_TURNS
+        .iter()
+        .find_map(|cycle| {
+            let index = cycle.iter().position(|&c| c == value)?;
+            Some(cycle[(index + 1) % 4])
+        })
+        .or_else(|| swap(value, &QUARTER_TURNS))
+        .or_else(|| {
+            let [up, right, down, left] = junction::arms(value)?;
+            junction::glyph([left, up, right, down], junction::is_rounded(value))
+        })
+        .unwrap_or(value)
+}
+
+/// A clockwise rotation by a multiple of 90 degrees.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Rotation {
+    Degrees90,
+    Degrees180,
+    Degrees270,
+}
+
+impl Rotation {
+    /// Returns the character that looks like `value` rotated by this [`Rotation`].
+    pub(crate) fn rotate_char(&self, value: char) -> char {
+        match self {
+            Rotation::Degrees90 => rotate_clockwise(value),
+            Rotation::Degrees180 => mirror_horizontal(mirror_vertical(value)),
+            Rotation::Degrees270 => rotate_clockwise(rotate_clockwise(rotate_clockwise(value))),
+        }
+    }
+
+    /// Returns the dimension of a grid with the given `dimension` after the rotation.
+    pub(crate) fn rotate_dimension(&self, dimension: Vec2) -> Vec2 {
+        match self {
+            Rotation::Degrees180 => dimension,
+            _ => Vec2::xy(dimension.y, dimension.x),
+        }
+    }
+
+    /// Returns the position, in a grid with the given `dimension`, of the cell that ends at
+    /// `rotated` after the rotation.
+    pub(crate) fn source(&self, rotated: Vec2, dimension: Vec2) -> Vec2 {
+        match self {
+            Rotation::Degrees90 => Vec2::xy(rotated.y, dimension.y - 1 - rotated.x),
+            Rotation::Degrees180 => dimension - Vec2::xy(1, 1) - rotated,
+            Rotation::Degrees270 => Vec2::xy(dimension.x - 1 - rotated.y, rotated.x),
+        }
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws every cell of the given `canvas`, with its colors and style, with its top-left
+    /// corner at the given `position`. This is useful to draw offscreen canvases, for example
+    /// after transforming them with [`Canvas::rotate`] or [`Canvas::scale`].
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_canvas(&mut self, canvas: &Canvas, position: Vec2) -> &mut Pencil<'a> {
+        let (foreground, background, style) = (self.foreground, self.background, self.style);
+        for y in 0..canvas.dimension().y {
+            for x in 0..canvas.dimension().x {
+                let pos = Vec2::xy(x, y);
+                if let Some(element) = canvas.elem(pos) {
+                    self.foreground = element.foreground;
+                    self.background = element.background;
+                    self.style = element.style;
+                    self.draw_element(self.origin + position + pos, element.value);
+                }
+            }
+        }
+        self.foreground = foreground;
+        self.background = background;
+        self.style = style;
+        self
+    }
+}
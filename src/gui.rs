@@ -1,22 +1,111 @@
 //! # GUI
 //!
-//! The `gui` module provides utilities for common GUI elements. Currently, it includes an
-//! [`FPSCounter`] that provides the user easy access to the application framerate without them
-//! needing to make any time calculations.
+//! The `gui` module provides utilities for common GUI elements. It includes an [`FPSCounter`]
+//! that provides the user easy access to the application framerate and frame time statistics
+//! without them needing to make any time calculations, and a set of widgets built on top of the
+//! [`Pencil`](crate::drawing::Pencil).
+//!
+//! Widgets implement the [`Widget`] trait. They keep their own state, receive the key events of
+//! each frame from the [`State`](crate::app::State) through a [`FocusManager`], which moves the
+//! focus between them with `Tab`, or with [`Widget::update`] when they are focused, and are drawn
+//! into a [`Rect`](crate::spatial::Rect) with
+//! [`Pencil::draw_widget`](crate::drawing::Pencil::draw_widget). The core widgets are
+//! [`Label`], [`Button`], [`Checkbox`], [`RadioGroup`], [`List`], [`Panel`] and [`ScrollView`],
+//! the text widgets [`TextInput`] and [`TextArea`], and the progress widgets [`ProgressBar`],
+//! [`Gauge`] and [`Spinner`].
+//!
+//! Widgets take their colors from the [`Theme`](crate::drawing::Theme) of the pencil that draws
+//! them, set with [`Pencil::set_theme`](crate::drawing::Pencil::set_theme).
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use ruscii::app::{App, State};
+//! # use ruscii::drawing::Pencil;
+//! # use ruscii::gui::{Button, Checkbox, FocusManager, Label, Panel, Widget};
+//! # use ruscii::spatial::{Rect, Vec2};
+//! # use ruscii::terminal::Window;
+//! #
+//! let mut app = App::default();
+//! let panel = Panel::new().title("Options").padding(1);
+//! let title = Label::new("Sound");
+//! let mut music = Checkbox::new("Music").checked(true);
+//! let mut quit = Button::new("Quit");
+//! let mut focus = FocusManager::new();
+//!
+//! app.run(|app_state: &mut State, window: &mut Window| {
+//!     focus.update(app_state, &mut [&mut music, &mut quit]);
+//!     if quit.clicked() {
+//!         app_state.stop();
+//!     }
+//!
+//!     let rect = Rect::new(Vec2::xy(2, 1), Vec2::xy(20, 7));
+//!     let inner = panel.inner(rect);
+//!     Pencil::new(window.canvas_mut())
+//!         .draw_widget(&panel, rect)
+//!         .draw_widget(&title, Rect::new(inner.position, Vec2::xy(inner.dimension.x, 1)))
+//!         .draw_widget(&music, Rect::new(inner.position + Vec2::y(1), music.measure()))
+//!         .draw_widget(&quit, Rect::new(inner.position + Vec2::y(2), quit.measure()));
+//! });
+//! ```
 //!
 //! More examples of use of the [`FPSCounter`] can be found in the
 //! [examples](https://github.com/lemunozm/ruscii/tree/master/examples) folder of the
 //! [`ruscii`](https://github.com/lemunozm/ruscii) repository.
 
-use std::time::SystemTime;
+mod button;
+mod dialog;
+mod focus;
+mod label;
+mod list;
+mod panel;
+mod progress;
+mod scroll;
+mod text;
+mod toggle;
+mod widget;
+
+pub use button::Button;
+pub use dialog::{Dialog, DialogResult, Modal};
+pub use focus::FocusManager;
+pub use label::Label;
+pub use list::List;
+pub use panel::Panel;
+pub use progress::{Gauge, ProgressBar, Spinner, SpinnerStyle};
+pub use scroll::ScrollView;
+pub use text::{TextArea, TextInput};
+pub use toggle::{Checkbox, RadioGroup};
+pub use widget::Widget;
 
-/// A struct that provides access to the application's framerate.
+use crate::app::State;
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The timings of a single frame, as recorded by an [`FPSCounter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameTiming {
+    /// The time between the start of the frame and the start of the next one.
+    pub frame: Duration,
+    /// The time spent in user code, see [`State::user_time`].
+    pub user: Duration,
+    /// The time spent drawing the window, see [`State::draw_time`].
+    pub draw: Duration,
+}
+
+/// A struct that provides access to the application's framerate and frame times.
 ///
 /// This FPS counter does not update automatically. [`FPSCounter::update`] should be called
 /// to update the FPS for every frame, i.e., in the `frame_action` function parameter of
-/// [`App::run`](super::app::App::run).
+/// [`App::run`](super::app::App::run). [`FPSCounter::update_with`] also records the time spent in
+/// user code and in [`Window::draw`](crate::terminal::Window::draw) from the [`State`].
 ///
-/// The FPS value can be obtained by calling [`FPSCounter::count`].
+/// The FPS value of the last second can be obtained by calling [`FPSCounter::count`]. The timings
+/// of the last frames are kept in a history, 120 frames long by default, from which the rolling
+/// [`FPSCounter::average`], [`FPSCounter::min`], [`FPSCounter::max`] and
+/// [`FPSCounter::percentile`] frame times are computed. [`FPSCounter::frame_times_millis`] is
+/// suitable to draw a frame-time graph with
+/// [`Pencil::draw_sparkline`](crate::drawing::Pencil::draw_sparkline).
 ///
 /// ## Example
 ///
@@ -31,39 +120,202 @@ use std::time::SystemTime;
 /// let mut fps_counter = FPSCounter::default();
 ///
 /// app.run(|app_state: &mut State, window: &mut Window| {
-///     fps_counter.update();  // Updates the FPS
+///     fps_counter.update_with(app_state);  // Updates the FPS and the frame times
 ///
 ///     let mut pencil = Pencil::new(window.canvas_mut());
 ///     pencil.draw_text(
 ///         &format!("FPS: {}", fps_counter.count()),  // Draws the FPS
 ///         Vec2::xy(1, 0)
 ///     );
+///     pencil.draw_sparkline(&fps_counter.frame_times_millis(), Vec2::xy(1, 1), 30);
 /// });
 /// ```
-#[derive(Default)]
 pub struct FPSCounter {
     fps: u32,
-    fps_time_stamp: u128,
+    fps_time_stamp: Option<Instant>,
     frame_counter: u32,
+    last_frame: Option<Instant>,
+    history: VecDeque<FrameTiming>,
+    history_size: usize,
+}
+
+impl Default for FPSCounter {
+    fn default() -> Self {
+        Self {
+            fps: 0,
+            fps_time_stamp: None,
+            frame_counter: 0,
+            last_frame: None,
+            history: VecDeque::new(),
+            history_size: 120,
+        }
+    }
 }
 
 impl FPSCounter {
+    /// Consumes the receiver and returns a new one that keeps the timings of the last `size`
+    /// frames, at least one.
+    pub fn history_size(mut self, size: usize) -> FPSCounter {
+        self.history_size = size.max(1);
+        self.trim();
+        self
+    }
+
     /// Retrieves the framerate and updates the [`FPSCounter`].
+    ///
+    /// The user and draw times of the recorded frame are zero. Use [`FPSCounter::update_with`]
+    /// inside [`App::run`](super::app::App::run) to record them.
     pub fn update(&mut self) {
-        let current_time = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_micros();
+        self.tick(Duration::ZERO, Duration::ZERO);
+    }
+
+    /// Retrieves the framerate and updates the [`FPSCounter`], recording the time spent in user
+    /// code and drawing the window from the `state`.
+    pub fn update_with(&mut self, state: &State) {
+        self.tick(*state.user_time(), *state.draw_time());
+    }
+
+    fn tick(&mut self, user: Duration, draw: Duration) {
+        let now = Instant::now();
+        let time_stamp = *self.fps_time_stamp.get_or_insert(now);
         self.frame_counter += 1;
-        if current_time - self.fps_time_stamp > 1000000 {
+        if now - time_stamp >= Duration::from_secs(1) {
             self.fps = self.frame_counter;
-            self.fps_time_stamp = current_time;
+            self.fps_time_stamp = Some(now);
             self.frame_counter = 0;
         }
+
+        if let Some(last_frame) = self.last_frame {
+            self.record(FrameTiming {
+                frame: now - last_frame,
+                user,
+                draw,
+            });
+        }
+        self.last_frame = Some(now);
+    }
+
+    /// Adds the timings of a frame to the history, discarding the oldest one if it is full.
+    ///
+    /// This is done by [`FPSCounter::update`], but it can be used to feed the counter from a
+    /// custom loop.
+    ///
+    /// ```rust
+    /// # use ruscii::gui::{FPSCounter, FrameTiming};
+    /// # use std::time::Duration;
+    /// #
+    /// let mut fps_counter = FPSCounter::default().history_size(4);
+    /// for ms in [10, 40, 20, 30, 50] {
+    ///     fps_counter.record(FrameTiming {
+    ///         frame: Duration::from_millis(ms),
+    ///         ..FrameTiming::default()
+    ///     });
+    /// }
+    /// assert_eq!(fps_counter.history().len(), 4);
+    /// assert_eq!(fps_counter.min(), Duration::from_millis(20));
+    /// assert_eq!(fps_counter.max(), Duration::from_millis(50));
+    /// assert_eq!(fps_counter.average(), Duration::from_millis(35));
+    /// assert_eq!(fps_counter.percentile(50.0), Duration::from_millis(30));
+    /// assert_eq!(fps_counter.frame_times_millis(), vec![40.0, 20.0, 30.0, 50.0]);
+    /// ```
+    pub fn record(&mut self, timing: FrameTiming) {
+        self.history.push_back(timing);
+        self.trim();
     }
 
-    /// Returns the framerate.
+    fn trim(&mut self) {
+        while self.history.len() > self.history_size {
+            self.history.pop_front();
+        }
+    }
+
+    /// Returns the framerate, counted over the last second.
     pub fn count(&self) -> u32 {
         self.fps
     }
+
+    /// Returns the framerate computed from the rolling [`FPSCounter::average`] frame time, or
+    /// zero if no frame has been recorded.
+    pub fn average_fps(&self) -> f32 {
+        let average = self.average().as_secs_f32();
+        if average > 0.0 {
+            1.0 / average
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the recorded frame timings, from the oldest to the newest.
+    pub fn history(&self) -> &VecDeque<FrameTiming> {
+        &self.history
+    }
+
+    /// Returns the recorded frame times in milliseconds, from the oldest to the newest.
+    pub fn frame_times_millis(&self) -> Vec<f64> {
+        self.history
+            .iter()
+            .map(|timing| timing.frame.as_secs_f64() * 1000.0)
+            .collect()
+    }
+
+    /// Returns the time of the last recorded frame, or zero if there is none.
+    pub fn frame_time(&self) -> Duration {
+        self.history
+            .back()
+            .map_or(Duration::ZERO, |timing| timing.frame)
+    }
+
+    /// Returns the average frame time of the history.
+    pub fn average(&self) -> Duration {
+        self.average_of(|timing| timing.frame)
+    }
+
+    /// Returns the average time spent in user code of the history.
+    pub fn average_user_time(&self) -> Duration {
+        self.average_of(|timing| timing.user)
+    }
+
+    /// Returns the average time spent drawing the window of the history.
+    pub fn average_draw_time(&self) -> Duration {
+        self.average_of(|timing| timing.draw)
+    }
+
+    fn average_of(&self, value: impl Fn(&FrameTiming) -> Duration) -> Duration {
+        if self.history.is_empty() {
+            return Duration::ZERO;
+        }
+        self.history.iter().map(value).sum::<Duration>() / self.history.len() as u32
+    }
+
+    /// Returns the shortest frame time of the history.
+    pub fn min(&self) -> Duration {
+        self.history
+            .iter()
+            .map(|timing| timing.frame)
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// Returns the longest frame time of the history.
+    pub fn max(&self) -> Duration {
+        self.history
+            .iter()
+            .map(|timing| timing.frame)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the frame time below which the given `percentile` of the history falls, with the
+    /// percentile between `0.0` and `100.0`, using the nearest-rank method.
+    ///
+    /// For example, `percentile(99.0)` is the frame time exceeded by only 1% of the frames.
+    pub fn percentile(&self, percentile: f32) -> Duration {
+        let mut frames: Vec<Duration> = self.history.iter().map(|timing| timing.frame).collect();
+        if frames.is_empty() {
+            return Duration::ZERO;
+        }
+        frames.sort_unstable();
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * frames.len() as f32).ceil() as usize;
+        frames[rank.clamp(1, frames.len()) - 1]
+    }
 }
@@ -0,0 +1,86 @@
+//! Buttons that can be activated.
+
+use super::widget::{truncate, with_focus};
+use super::Widget;
+use crate::drawing::Pencil;
+use crate::keyboard::{Key, KeyEvent};
+use crate::spatial::{Rect, Vec2};
+
+/// A focusable [`Widget`] that is activated with `Enter` or `Space` while it has the focus.
+///
+/// ```rust
+/// # use ruscii::gui::{Button, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// #
+/// let mut button = Button::new("Start");
+/// assert_eq!(button.measure().x, 9);
+///
+/// button.handle_event(&KeyEvent::Pressed(Key::Enter));
+/// assert!(button.clicked());
+/// assert!(!button.clicked());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Button {
+    label: String,
+    focused: bool,
+    clicked: bool,
+}
+
+impl Button {
+    /// Creates a [`Button`] with the given `label`, drawn as `[ label ]`.
+    pub fn new(label: &str) -> Button {
+        Button {
+            label: label.to_string(),
+            focused: false,
+            clicked: false,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    /// Returns `true` if the button has been activated since the last call.
+    pub fn clicked(&mut self) -> bool {
+        std::mem::take(&mut self.clicked)
+    }
+}
+
+impl Widget for Button {
+    fn measure(&self) -> Vec2 {
+        Vec2::xy(self.label.chars().count() + 4, 1)
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let text = truncate(&format!("[ {} ]", self.label), rect.dimension.x);
+        with_focus(pencil, self.focused, |pencil| {
+            pencil.draw_text(&text, rect.position);
+        });
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        match event {
+            KeyEvent::Pressed(Key::Enter) | KeyEvent::Pressed(Key::Space) => {
+                self.clicked = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
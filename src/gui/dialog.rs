@@ -0,0 +1,377 @@
+//! Modal dialogs: confirmations, alerts and prompts.
+
+use super::{Button, FocusManager, TextInput, Widget};
+use crate::app::State;
+use crate::drawing::{Pencil, RectCharset, TextLayout};
+use crate::keyboard::{Key, KeyEvent};
+use crate::spatial::{Rect, Vec2};
+
+/// The choice made by the user in a [`Dialog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogResult {
+    /// A confirmation was answered with `Yes` (`true`) or `No` (`false`).
+    Confirmed(bool),
+    /// An alert was acknowledged with `OK`.
+    Dismissed,
+    /// A prompt was accepted with the given text.
+    Submitted(String),
+    /// The dialog was closed with `Esc`, or a prompt with `Cancel`.
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Choice {
+    Yes,
+    No,
+    Ok,
+    Cancel,
+}
+
+/// A box with a title, a message and a row of buttons, and optionally a [`TextInput`], that is
+/// drawn centered over the rect given to [`Widget::draw`] with a border and a drop shadow.
+///
+/// The dialog moves the focus between its input and its buttons with a [`FocusManager`]. Its
+/// result is returned by [`Dialog::handle_event`] or [`Dialog::update`] once the user makes a
+/// choice. A [`Modal`] can hold the open dialog of an application.
+///
+/// ```rust
+/// # use ruscii::gui::{Dialog, DialogResult};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// #
+/// let mut dialog = Dialog::confirm("Quit", "Do you want to leave the game?");
+/// assert_eq!(dialog.handle_event(&KeyEvent::Pressed(Key::Right)), None);
+/// assert_eq!(
+///     dialog.handle_event(&KeyEvent::Pressed(Key::Enter)),
+///     Some(DialogResult::Confirmed(false))
+/// );
+/// ```
+///
+/// The cells under the dialog are cleared before it is drawn:
+///
+/// ```rust
+/// # use ruscii::drawing::Pencil;
+/// # use ruscii::gui::Dialog;
+/// # use ruscii::spatial::{Rect, Vec2};
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let mut canvas = Canvas::new(Vec2::xy(60, 20), &VisualElement::default());
+/// let dialog = Dialog::alert("Game over", "No lives left");
+/// Pencil::new(&mut canvas)
+///     .draw_filled_rect('x', Vec2::zero(), Vec2::xy(60, 20))
+///     .draw_widget(&dialog, Rect::from_dimension(Vec2::xy(60, 20)));
+///
+/// assert_eq!(canvas.elem(Vec2::xy(12, 8)).unwrap().value, ' ');
+/// assert_eq!(canvas.elem(Vec2::xy(0, 0)).unwrap().value, 'x');
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dialog {
+    title: String,
+    message: String,
+    input: Option<TextInput>,
+    buttons: Vec<(Button, Choice)>,
+    focus: FocusManager,
+    charset: RectCharset,
+    width: i32,
+}
+
+impl Dialog {
+    fn new(title: &str, message: &str, choices: &[(&str, Choice)]) -> Dialog {
+        Dialog {
+            title: title.to_string(),
+            message: message.to_string(),
+            input: None,
+            buttons: choices
+                .iter()
+                .map(|&(label, choice)| (Button::new(label), choice))
+                .collect(),
+            focus: FocusManager::new(),
+            charset: RectCharset::double_lines(),
+            width: 40,
+        }
+    }
+
+    /// Creates a [`Dialog`] that asks a question with the buttons `Yes` and `No`, and results in
+    /// [`DialogResult::Confirmed`].
+    pub fn confirm(title: &str, message: &str) -> Dialog {
+        Dialog::new(title, message, &[("Yes", Choice::Yes), ("No", Choice::No)])
+    }
+
+    /// Creates a [`Dialog`] that shows a message with the button `OK`, and results in
+    /// [`DialogResult::Dismissed`].
+    pub fn alert(title: &str, message: &str) -> Dialog {
+        Dialog::new(title, message, &[("OK", Choice::Ok)])
+    }
+
+    /// Creates a [`Dialog`] that asks for a text with a [`TextInput`] and the buttons `OK` and
+    /// `Cancel`, and results in [`DialogResult::Submitted`] when accepted with `OK` or `Enter`.
+    pub fn prompt(title: &str, message: &str) -> Dialog {
+        let choices = [("OK", Choice::Ok), ("Cancel", Choice::Cancel)];
+        Dialog::new(title, message, &choices).input(TextInput::new())
+    }
+
+    /// Consumes the receiver and returns a new one that edits its text with the given `input`,
+    /// which can have a maximum length or a password mask.
+    pub fn input(mut self, input: TextInput) -> Dialog {
+        self.input = Some(input);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with its border drawn with the given
+    /// [`RectCharset`]. By default, it is drawn with [`RectCharset::double_lines`].
+    pub fn charset(mut self, charset: RectCharset) -> Dialog {
+        self.charset = charset;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with the given `width`, including the
+    /// border. The default width is 40.
+    pub fn width(mut self, width: i32) -> Dialog {
+        self.width = width;
+        self
+    }
+
+    /// Returns the text of the [`TextInput`] of a prompt.
+    pub fn text(&self) -> Option<String> {
+        self.input.as_ref().map(|input| input.text())
+    }
+
+    /// Passes the key events of the current frame from the given `state` to
+    /// [`Dialog::handle_event`], and returns the result of the first one that closes the dialog.
+    pub fn update(&mut self, state: &State) -> Option<DialogResult> {
+        let events = state.keyboard().last_key_events();
+        events.iter().find_map(|event| self.handle_event(event))
+    }
+
+    /// Handles a key event. Returns the result of the dialog if the event closes it.
+    pub fn handle_event(&mut self, event: &KeyEvent) -> Option<DialogResult> {
+        if let KeyEvent::Pressed(Key::Esc) = event {
+            return Some(DialogResult::Cancelled);
+        }
+        let Dialog {
+            input,
+            buttons,
+            focus,
+            ..
+        } = self;
+        let mut widgets: Vec<&mut dyn Widget> = Vec::new();
+        if let Some(input) = input {
+            widgets.push(input);
+        }
+        for (button, _) in buttons.iter_mut() {
+            widgets.push(button);
+        }
+        focus.handle_event(event, &mut widgets);
+
+        if let Some(text) = self.input.as_mut().and_then(|input| input.submitted()) {
+            return Some(DialogResult::Submitted(text));
+        }
+        let choice = self
+            .buttons
+            .iter_mut()
+            .find_map(|(button, choice)| button.clicked().then_some(*choice))?;
+        Some(match choice {
+            Choice::Yes => DialogResult::Confirmed(true),
+            Choice::No => DialogResult::Confirmed(false),
+            Choice::Ok => match &self.input {
+                Some(input) => DialogResult::Submitted(input.text()),
+                None => DialogResult::Dismissed,
+            },
+            Choice::Cancel => DialogResult::Cancelled,
+        })
+    }
+
+    /// Returns the lines of the message wrapped to the inner width of the dialog.
+    fn message_lines(&self, width: i32) -> Vec<String> {
+        TextLayout::default().lines(&self.message, (width - 4).max(1) as usize)
+    }
+
+    fn height(&self, lines: usize) -> i32 {
+        let input = if self.input.is_some() { 2 } else { 0 };
+        lines as i32 + input + 5
+    }
+}
+
+impl Widget for Dialog {
+    fn measure(&self) -> Vec2 {
+        Vec2::xy(
+            self.width,
+            self.height(self.message_lines(self.width).len()),
+        )
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let width = self.width.min(rect.dimension.x);
+        let lines = self.message_lines(width);
+        let dialog = rect.centered(Vec2::xy(width, self.height(lines.len())));
+        let inner = Rect::new(
+            dialog.position + Vec2::xy(2, 2),
+            Vec2::xy(width - 4, dialog.dimension.y - 4),
+        );
+
+        let foreground = *pencil.foreground();
+        let theme = *pencil.theme();
+        pencil
+            .draw_shadow(dialog.position, dialog.dimension, Vec2::xy(2, 1), 0.5)
+            .draw_filled_rect(' ', dialog.position, dialog.dimension)
+            .set_foreground(theme.border)
+            .draw_rect(&self.charset, dialog.position, dialog.dimension);
+        if !self.title.is_empty() {
+            let title: String = format!(" {} ", self.title)
+                .chars()
+                .take((width - 4).max(0) as usize)
+                .collect();
+            pencil
+                .set_foreground(theme.accent)
+                .draw_text(&title, dialog.position + Vec2::x(2));
+        }
+        pencil.set_foreground(foreground);
+        for (i, line) in lines.iter().enumerate() {
+            pencil.draw_text(line, inner.position + Vec2::y(i));
+        }
+
+        let mut row = inner.position.y + lines.len() as i32 + 1;
+        if let Some(input) = &self.input {
+            let input_rect = Rect::new(
+                Vec2::xy(inner.position.x, row),
+                Vec2::xy(inner.dimension.x, 1),
+            );
+            let background = *pencil.background();
+            pencil
+                .set_background(background.lighten(0.2))
+                .draw_filled_rect(' ', input_rect.position, input_rect.dimension)
+                .draw_widget(input, input_rect)
+                .set_background(background);
+            row += 2;
+        }
+
+        let gap = 2;
+        let widths: Vec<i32> = self.buttons.iter().map(|(b, _)| b.measure().x).collect();
+        let total = widths.iter().sum::<i32>() + gap * (widths.len() as i32 - 1).max(0);
+        let mut x = inner.position.x + (inner.dimension.x - total) / 2;
+        for ((button, _), width) in self.buttons.iter().zip(widths) {
+            pencil.draw_widget(button, Rect::new(Vec2::xy(x, row), Vec2::xy(width, 1)));
+            x += width + gap;
+        }
+    }
+}
+
+/// Holds the [`Dialog`] open in an application, if any, tagged with a value of type `T` that
+/// tells which question it asks. This way, a single field of the game state can handle all its
+/// dialogs.
+///
+/// While a dialog is open, [`Modal::update`] captures the key events, and the game is expected
+/// to skip its own input, which [`Modal::is_open`] tells.
+///
+/// ```rust,no_run
+/// # use ruscii::app::{App, State};
+/// # use ruscii::drawing::Pencil;
+/// # use ruscii::gui::{Dialog, DialogResult, Modal};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// # use ruscii::spatial::Rect;
+/// # use ruscii::terminal::Window;
+/// #
+/// enum Question {
+///     Quit,
+///     Name,
+/// }
+///
+/// let mut app = App::default();
+/// let mut modal = Modal::new();
+/// let mut name = String::from("Player");
+///
+/// app.run(|app_state: &mut State, window: &mut Window| {
+///     match modal.update(app_state) {
+///         Some((Question::Quit, DialogResult::Confirmed(true))) => app_state.stop(),
+///         Some((Question::Name, DialogResult::Submitted(text))) => name = text,
+///         _ => (),
+///     }
+///     if !modal.is_open() {
+///         for key_event in app_state.keyboard().last_key_events() {
+///             match key_event {
+///                 KeyEvent::Pressed(Key::Esc) => {
+///                     modal.open(Question::Quit, Dialog::confirm("Quit", "Leave the game?"))
+///                 }
+///                 KeyEvent::Pressed(Key::N) => {
+///                     modal.open(Question::Name, Dialog::prompt("Name", "Enter your name:"))
+///                 }
+///                 _ => (),
+///             }
+///         }
+///     }
+///
+///     let screen = Rect::from_dimension(window.size());
+///     let mut pencil = Pencil::new(window.canvas_mut());
+///     pencil.draw_text(&format!("Hello, {}!", name), screen.center());
+///     modal.draw(&mut pencil, screen);
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Modal<T> {
+    open: Option<(T, Dialog)>,
+    dim: f32,
+}
+
+impl<T> Modal<T> {
+    /// Creates a [`Modal`] without an open dialog.
+    pub fn new() -> Modal<T> {
+        Modal {
+            open: None,
+            dim: 0.4,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that darkens the frame behind the dialog by
+    /// the given `amount`, from `0.0` (unchanged) to `1.0` (black). The default is `0.4`.
+    pub fn dim(mut self, amount: f32) -> Modal<T> {
+        self.dim = amount;
+        self
+    }
+
+    /// Opens the given `dialog` tagged with `tag`, replacing the open one, if any.
+    pub fn open(&mut self, tag: T, dialog: Dialog) {
+        self.open = Some((tag, dialog));
+    }
+
+    /// Closes the open dialog without a result.
+    pub fn close(&mut self) {
+        self.open = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Returns the tag and the open dialog, if any.
+    pub fn dialog(&self) -> Option<(&T, &Dialog)> {
+        self.open.as_ref().map(|(tag, dialog)| (tag, dialog))
+    }
+
+    /// Passes the key events of the current frame from the given `state` to the open dialog.
+    /// When the dialog closes, returns its tag and result.
+    pub fn update(&mut self, state: &State) -> Option<(T, DialogResult)> {
+        let events = state.keyboard().last_key_events();
+        events.iter().find_map(|event| self.handle_event(event))
+    }
+
+    /// Passes a key event to the open dialog. When the dialog closes, returns its tag and
+    /// result.
+    pub fn handle_event(&mut self, event: &KeyEvent) -> Option<(T, DialogResult)> {
+        let result = self.open.as_mut()?.1.handle_event(event)?;
+        self.open.take().map(|(tag, _)| (tag, result))
+    }
+
+    /// Darkens the given `rect` and draws the open dialog, if any, centered over it.
+    pub fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        if let Some((_, dialog)) = &self.open {
+            pencil
+                .darken_rect(rect.position, rect.dimension, self.dim)
+                .draw_widget(dialog, rect);
+        }
+    }
+}
+
+impl<T> Default for Modal<T> {
+    fn default() -> Self {
+        Modal::new()
+    }
+}
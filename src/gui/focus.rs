@@ -0,0 +1,187 @@
+//! Focus tracking and keyboard navigation across widgets.
+
+use super::widget::Modifiers;
+use super::Widget;
+use crate::app::State;
+use crate::keyboard::{Key, KeyEvent};
+
+/// Decides which [`Widget`] receives the key events and moves the focus between widgets.
+///
+/// The widgets stay owned by the application and are passed to the [`FocusManager`] each frame,
+/// in focus order, with [`FocusManager::update`]. The focused widget receives the key events
+/// first. The events it does not use move the focus:
+///
+/// - `Tab` focuses the next focusable widget and `Shift+Tab` the previous one, wrapping around.
+/// - The arrow keys `Down` and `Right` focus the next widget and `Up` and `Left` the previous one,
+///   unless disabled with [`FocusManager::arrows`].
+///
+/// Scopes trap the focus, for example in a dialog: [`FocusManager::push_scope`] starts a new
+/// scope, where the widgets passed are only the ones of the dialog, and
+/// [`FocusManager::pop_scope`] returns to the previous scope with its focus restored.
+///
+/// Focused widgets draw the part that the keys act on with the selection colors of the
+/// [`Theme`](crate::drawing::Theme) of the pencil, and text widgets also show the terminal cursor.
+///
+/// ```rust
+/// # use ruscii::gui::{Button, FocusManager, Label, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// #
+/// let mut title = Label::new("Quit?");
+/// let mut yes = Button::new("Yes");
+/// let mut no = Button::new("No");
+/// let mut focus = FocusManager::new();
+///
+/// let mut widgets: [&mut dyn Widget; 3] = [&mut title, &mut yes, &mut no];
+/// focus.handle_event(&KeyEvent::Pressed(Key::Tab), &mut widgets);
+/// assert_eq!(focus.focused(), Some(2));
+///
+/// focus.handle_event(&KeyEvent::Pressed(Key::Enter), &mut widgets);
+/// assert!(no.clicked());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FocusManager {
+    scopes: Vec<Option<usize>>,
+    arrows: bool,
+    modifiers: Modifiers,
+}
+
+impl FocusManager {
+    /// Creates a [`FocusManager`] with a single scope and nothing focused. The first focusable
+    /// widget gets the focus on the first update.
+    pub fn new() -> FocusManager {
+        FocusManager {
+            scopes: vec![None],
+            arrows: true,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    /// Consumes the receiver and returns a new one where the arrow keys move the focus, or not.
+    /// Enabled by default.
+    pub fn arrows(mut self, enable: bool) -> FocusManager {
+        self.arrows = enable;
+        self
+    }
+
+    /// Returns the index of the focused widget in the current scope.
+    pub fn focused(&self) -> Option<usize> {
+        *self.scopes.last().unwrap()
+    }
+
+    /// Returns the number of scopes, starting at 1.
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Focuses the widget with the given `index` if it is focusable.
+    pub fn focus(&mut self, widgets: &mut [&mut dyn Widget], index: usize) {
+        if widgets
+            .get(index)
+            .is_some_and(|widget| widget.is_focusable())
+        {
+            *self.scopes.last_mut().unwrap() = Some(index);
+        }
+        self.sync(widgets);
+    }
+
+    /// Focuses the next focusable widget, wrapping around.
+    pub fn focus_next(&mut self, widgets: &mut [&mut dyn Widget]) {
+        self.step(widgets, 1);
+    }
+
+    /// Focuses the previous focusable widget, wrapping around.
+    pub fn focus_previous(&mut self, widgets: &mut [&mut dyn Widget]) {
+        self.step(widgets, -1);
+    }
+
+    /// Starts a new scope, where the focus is trapped until [`FocusManager::pop_scope`] is
+    /// called. The given `widgets` of the current scope lose the focus.
+    pub fn push_scope(&mut self, widgets: &mut [&mut dyn Widget]) {
+        for widget in widgets.iter_mut() {
+            widget.set_focused(false);
+        }
+        self.scopes.push(None);
+    }
+
+    /// Ends the current scope and restores the focus of the previous one to the given `widgets`.
+    /// The first scope is never removed.
+    pub fn pop_scope(&mut self, widgets: &mut [&mut dyn Widget]) {
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+        self.sync(widgets);
+    }
+
+    /// Passes the key events of the current frame from the given `state` to
+    /// [`FocusManager::handle_event`], and the mouse wheel to the [`Widget::handle_wheel`] of the
+    /// focused widget.
+    pub fn update(&mut self, state: &State, widgets: &mut [&mut dyn Widget]) {
+        self.sync(widgets);
+        for event in state.keyboard().last_key_events() {
+            self.handle_event(event, widgets);
+        }
+        let wheel = state.keyboard().last_wheel_delta();
+        if let Some(focused) = self.focused().filter(|_| wheel != 0) {
+            widgets[focused].handle_wheel(wheel);
+        }
+    }
+
+    /// Passes a key event to the focused widget, or uses it to move the focus if the widget does
+    /// not. Returns `true` if the event was used.
+    pub fn handle_event(&mut self, event: &KeyEvent, widgets: &mut [&mut dyn Widget]) -> bool {
+        self.modifiers.update(event);
+        self.sync(widgets);
+        if let Some(focused) = self.focused() {
+            if widgets[focused].handle_event(event) {
+                return true;
+            }
+        }
+        let step = match event {
+            KeyEvent::Pressed(Key::Tab) if self.modifiers.shift => -1,
+            KeyEvent::Pressed(Key::Tab) => 1,
+            KeyEvent::Pressed(Key::Down) | KeyEvent::Pressed(Key::Right) if self.arrows => 1,
+            KeyEvent::Pressed(Key::Up) | KeyEvent::Pressed(Key::Left) if self.arrows => -1,
+            _ => return false,
+        };
+        self.step(widgets, step);
+        true
+    }
+
+    fn step(&mut self, widgets: &mut [&mut dyn Widget], step: i32) {
+        let len = widgets.len() as i32;
+        let start = match self.focused() {
+            Some(focused) => focused as i32,
+            None if step > 0 => -1,
+            None => len,
+        };
+        let next = (1..=len)
+            .map(|i| (start + i * step).rem_euclid(len) as usize)
+            .find(|&i| widgets[i].is_focusable());
+        if next.is_some() {
+            *self.scopes.last_mut().unwrap() = next;
+        }
+        self.sync(widgets);
+    }
+
+    /// Makes sure that the focused widget is focusable, focusing the first focusable one
+    /// otherwise, and that only it has its focus set.
+    fn sync(&mut self, widgets: &mut [&mut dyn Widget]) {
+        let valid = self
+            .focused()
+            .filter(|&i| widgets.get(i).is_some_and(|widget| widget.is_focusable()));
+        let focused = valid.or_else(|| widgets.iter().position(|widget| widget.is_focusable()));
+        *self.scopes.last_mut().unwrap() = focused;
+        for (i, widget) in widgets.iter_mut().enumerate() {
+            let focus = Some(i) == focused;
+            if widget.is_focused() != focus {
+                widget.set_focused(focus);
+            }
+        }
+    }
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        FocusManager::new()
+    }
+}
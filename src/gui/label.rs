@@ -0,0 +1,61 @@
+//! Static text.
+
+use super::Widget;
+use crate::drawing::{Pencil, TextLayout};
+use crate::spatial::{Rect, Vec2};
+use crate::terminal::Color;
+
+/// A [`Widget`] that shows a text, which can have several lines and is laid out with a
+/// [`TextLayout`]. It is not focusable.
+#[derive(Debug, Clone)]
+pub struct Label {
+    text: String,
+    layout: TextLayout,
+    foreground: Option<Color>,
+}
+
+impl Label {
+    /// Creates a [`Label`] with the given `text` and the default [`TextLayout`].
+    pub fn new(text: &str) -> Label {
+        Label {
+            text: text.to_string(),
+            layout: TextLayout::default(),
+            foreground: None,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one laid out with the given [`TextLayout`].
+    pub fn layout(mut self, layout: TextLayout) -> Label {
+        self.layout = layout;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one drawn with the given `color`. Without a
+    /// color, the label takes the color of the [`Pencil`].
+    pub fn foreground(mut self, color: Color) -> Label {
+        self.foreground = Some(color);
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+}
+
+impl Widget for Label {
+    fn measure(&self) -> Vec2 {
+        let width = self.text.lines().map(|l| l.chars().count()).max();
+        Vec2::xy(width.unwrap_or(0), self.text.lines().count())
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let foreground = *pencil.foreground();
+        pencil.set_foreground(self.foreground.unwrap_or(foreground));
+        pencil.draw_text_box(&self.text, rect.position, rect.dimension, &self.layout);
+        pencil.set_foreground(foreground);
+    }
+}
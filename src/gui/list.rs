@@ -0,0 +1,382 @@
+//! Lists of selectable items, such as game menus.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use super::widget::{draw_scrollbar, truncate, with_focus};
+use super::Widget;
+use crate::drawing::Pencil;
+use crate::keyboard::{Key, KeyEvent};
+use crate::spatial::{Rect, Vec2};
+use crate::terminal::Color;
+
+/// The time after which the characters typed to search for an item are forgotten.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+#[derive(Debug, Clone)]
+struct Item {
+    text: String,
+    enabled: bool,
+}
+
+/// A focusable [`Widget`] with a list of items, one per line, of which one is selected and
+/// highlighted. It can be used as a game menu.
+///
+/// While focused, it handles these keys:
+///
+/// - `Up` and `Down` select the previous or the next item, wrapping around the ends of the list
+///   if [`List::wrap`] is enabled. `PageUp` and `PageDown` move by the lines visible, and `Home`
+///   and `End` select the first or the last item.
+/// - Printable keys search for the next item that starts with the characters typed within the
+///   last second, ignoring case.
+/// - `Enter` and `Space` activate the selected item, which is then returned by
+///   [`List::activated`].
+///
+/// Disabled items are drawn dimmed and are skipped by the selection. If the items do not fit in
+/// the rect, the list scrolls to keep the selected item visible and shows a scrollbar.
+///
+/// ```rust
+/// # use ruscii::gui::{List, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// #
+/// let mut menu = List::new(&["New game", "Continue", "Options", "Quit"]).disabled(&[1]);
+/// menu.handle_event(&KeyEvent::Pressed(Key::Down));
+/// assert_eq!(menu.selected_item(), Some("Options"));
+///
+/// menu.handle_event(&KeyEvent::Pressed(Key::Q));
+/// menu.handle_event(&KeyEvent::Pressed(Key::Enter));
+/// assert_eq!(menu.activated(), Some(3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct List {
+    items: Vec<Item>,
+    selected: Option<usize>,
+    activated: Option<usize>,
+    wrap: bool,
+    scrollbar: bool,
+    highlight: Option<(Color, Color)>,
+    search: String,
+    search_time: Option<Instant>,
+    scroll: Cell<usize>,
+    page: Cell<usize>,
+    focused: bool,
+}
+
+impl List {
+    /// Creates a [`List`] with the given `items`, all enabled, and the first one selected.
+    pub fn new(items: &[&str]) -> List {
+        let mut list = List {
+            items: Vec::new(),
+            selected: None,
+            activated: None,
+            wrap: true,
+            scrollbar: true,
+            highlight: None,
+            search: String::new(),
+            search_time: None,
+            scroll: Cell::new(0),
+            page: Cell::new(1),
+            focused: false,
+        };
+        for item in items {
+            list.push_item(item);
+        }
+        list
+    }
+
+    /// Consumes the receiver and returns a new one where the selection wraps around the ends of
+    /// the list, or stops at them. Enabled by default.
+    pub fn wrap(mut self, enable: bool) -> List {
+        self.wrap = enable;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that shows a scrollbar when the items do not
+    /// fit, or not. Enabled by default.
+    pub fn scrollbar(mut self, enable: bool) -> List {
+        self.scrollbar = enable;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that draws the selected item with the given
+    /// colors. By default, it takes the selection colors of the [`Theme`] of the [`Pencil`].
+    ///
+    /// [`Theme`]: crate::drawing::Theme
+    pub fn highlight(mut self, foreground: Color, background: Color) -> List {
+        self.highlight = Some((foreground, background));
+        self
+    }
+
+    /// Consumes the receiver and returns a new one where the items with the given indices are
+    /// disabled.
+    pub fn disabled(mut self, indices: &[usize]) -> List {
+        for &index in indices {
+            self.set_enabled(index, false);
+        }
+        self
+    }
+
+    /// Adds an enabled item with the given `text` to the end of the list.
+    pub fn push_item(&mut self, text: &str) -> &mut List {
+        self.items.push(Item {
+            text: text.to_string(),
+            enabled: true,
+        });
+        if self.selected.is_none() {
+            self.selected = Some(self.items.len() - 1);
+        }
+        self
+    }
+
+    /// Removes all the items.
+    pub fn clear(&mut self) -> &mut List {
+        self.items.clear();
+        self.selected = None;
+        self.activated = None;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the text of the item with the given `index`.
+    pub fn item(&self, index: usize) -> Option<&str> {
+        self.items.get(index).map(|item| item.text.as_str())
+    }
+
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.items.get(index).is_some_and(|item| item.enabled)
+    }
+
+    /// Enables or disables the item with the given `index`. If the selected item is disabled,
+    /// the next enabled one is selected.
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.enabled = enabled;
+        }
+        if self
+            .selected
+            .is_none_or(|selected| !self.is_enabled(selected))
+        {
+            let start = self.selected.unwrap_or(0);
+            self.selected = self
+                .find(start, 1, true)
+                .or_else(|| self.find(start, -1, false));
+        }
+    }
+
+    /// Returns the index of the selected item, or [`None`] if no item is enabled.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the text of the selected item.
+    pub fn selected_item(&self) -> Option<&str> {
+        self.item(self.selected?)
+    }
+
+    /// Selects the item with the given `index` if it is enabled.
+    pub fn set_selected(&mut self, index: usize) {
+        if self.is_enabled(index) {
+            self.selected = Some(index);
+        }
+    }
+
+    /// Returns the index of the item activated with `Enter` or `Space` since the last call, if
+    /// any.
+    pub fn activated(&mut self) -> Option<usize> {
+        self.activated.take()
+    }
+
+    /// Returns the first enabled item from `start` in the given `step` direction, including
+    /// `start` itself if `inclusive`. Wraps around the ends of the list if enabled.
+    fn find(&self, start: usize, step: i32, inclusive: bool) -> Option<usize> {
+        let len = self.items.len() as i32;
+        let mut index = start as i32;
+        if !inclusive {
+            index += step;
+        }
+        for _ in 0..len {
+            if index < 0 || index >= len {
+                if !self.wrap {
+                    return None;
+                }
+                index = index.rem_euclid(len);
+            }
+            if self.items[index as usize].enabled {
+                return Some(index as usize);
+            }
+            index += step;
+        }
+        None
+    }
+
+    /// Selects the item `lines` away from the selected one, or the nearest enabled one to it,
+    /// without wrapping around.
+    fn move_by(&mut self, lines: i32) -> bool {
+        let last = self.items.len().saturating_sub(1) as i32;
+        let target = match self.selected {
+            Some(selected) => (selected as i32 + lines).clamp(0, last) as usize,
+            None => return false,
+        };
+        let step = lines.signum();
+        let wrap = std::mem::replace(&mut self.wrap, false);
+        let found = self
+            .find(target, step, true)
+            .or_else(|| self.find(target, -step, true));
+        self.wrap = wrap;
+        let changed = found != self.selected;
+        self.selected = found.or(self.selected);
+        changed
+    }
+
+    fn search(&mut self, value: char) -> bool {
+        let now = Instant::now();
+        if self
+            .search_time
+            .is_none_or(|time| now - time > TYPE_AHEAD_TIMEOUT)
+        {
+            self.search.clear();
+        }
+        self.search_time = Some(now);
+        self.search.extend(value.to_lowercase());
+
+        // Typing the same character again goes to the next item that starts with it.
+        let repeated = self.search.chars().all(|c| self.search.starts_with(c));
+        let search = if repeated {
+            self.search.chars().take(1).collect()
+        } else {
+            self.search.clone()
+        };
+        let start = self.selected.unwrap_or(0);
+        let len = self.items.len();
+        let found = (0..len)
+            .map(|i| (start + i + repeated as usize) % len)
+            .find(|&i| {
+                let item = &self.items[i];
+                item.enabled && item.text.to_lowercase().starts_with(&search)
+            });
+        if found.is_some() {
+            self.selected = found;
+        }
+        found.is_some()
+    }
+}
+
+impl Widget for List {
+    fn measure(&self) -> Vec2 {
+        let width = self
+            .items
+            .iter()
+            .map(|item| item.text.chars().count())
+            .max();
+        Vec2::xy(width.unwrap_or(0), self.items.len())
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let height = rect.dimension.y.max(0) as usize;
+        if height == 0 {
+            return;
+        }
+        let overflow = self.items.len() > height;
+        let width = match self.scrollbar && overflow {
+            true => rect.dimension.x - 1,
+            false => rect.dimension.x,
+        };
+        let mut scroll = self
+            .scroll
+            .get()
+            .min(self.items.len().saturating_sub(height));
+        if let Some(selected) = self.selected {
+            if selected < scroll {
+                scroll = selected;
+            } else if selected >= scroll + height {
+                scroll = selected + 1 - height;
+            }
+        }
+        self.scroll.set(scroll);
+        self.page.set(height);
+
+        let (foreground, background) = (*pencil.foreground(), *pencil.background());
+        for (row, item) in self.items.iter().enumerate().skip(scroll).take(height) {
+            let text = truncate(&item.text, width);
+            let position = rect.position + Vec2::y(row - scroll);
+            if !item.enabled {
+                pencil.set_foreground(pencil.theme().disabled);
+            }
+            if Some(row) == self.selected {
+                let text = format!("{:<1$}", text, width.max(0) as usize);
+                match self.highlight {
+                    Some((fg, bg)) => {
+                        pencil.set_foreground(fg).set_background(bg);
+                        pencil.draw_text(&text, position);
+                    }
+                    None => with_focus(pencil, true, |pencil| {
+                        pencil.draw_text(&text, position);
+                    }),
+                }
+            } else {
+                pencil.draw_text(&text, position);
+            }
+            pencil.set_foreground(foreground).set_background(background);
+        }
+
+        if self.scrollbar && overflow {
+            let position = rect.position + Vec2::x(rect.dimension.x - 1);
+            draw_scrollbar(
+                pencil,
+                position,
+                height,
+                self.items.len(),
+                height,
+                scroll,
+                true,
+            );
+        }
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        let key = match event.pressed() {
+            Some(key) => key,
+            None => return false,
+        };
+        let page = self.page.get().max(1) as i32;
+        let previous = self.selected;
+        match key {
+            Key::Up => self.selected = previous.and_then(|s| self.find(s, -1, false)).or(previous),
+            Key::Down => self.selected = previous.and_then(|s| self.find(s, 1, false)).or(previous),
+            Key::PageUp => return self.move_by(-page),
+            Key::PageDown => return self.move_by(page),
+            Key::Home => return self.move_by(-(self.items.len() as i32)),
+            Key::End => return self.move_by(self.items.len() as i32),
+            Key::Enter | Key::Space => {
+                self.activated = self.selected;
+                return self.selected.is_some();
+            }
+            key => {
+                return match key.to_char(false) {
+                    Some(value) => self.search(value),
+                    None => false,
+                }
+            }
+        }
+        self.selected != previous
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,96 @@
+//! Bordered frames that group other widgets.
+
+use super::widget::truncate;
+use super::Widget;
+use crate::drawing::{Pencil, RectCharset};
+use crate::spatial::{Rect, Vec2};
+
+/// A [`Widget`] that draws a border with an optional title around an area where other widgets
+/// are drawn. It is not focusable.
+///
+/// ```rust
+/// # use ruscii::gui::{Label, Panel, Widget};
+/// # use ruscii::drawing::Pencil;
+/// # use ruscii::spatial::{Rect, Vec2};
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let panel = Panel::new().title("Stats").padding(1);
+/// let rect = Rect::new(Vec2::zero(), Vec2::xy(16, 5));
+/// assert_eq!(panel.inner(rect), Rect::new(Vec2::xy(2, 2), Vec2::xy(12, 1)));
+///
+/// let mut canvas = Canvas::new(Vec2::xy(16, 5), &VisualElement::default());
+/// Pencil::new(&mut canvas)
+///     .draw_widget(&panel, rect)
+///     .draw_widget(&Label::new("HP 10/10"), panel.inner(rect));
+/// assert_eq!(canvas.elem(Vec2::xy(3, 0)).unwrap().value, 'S');
+/// ```
+#[derive(Debug, Clone)]
+pub struct Panel {
+    title: Option<String>,
+    charset: RectCharset,
+    padding: i32,
+}
+
+impl Panel {
+    /// Creates a [`Panel`] without title, drawn with [`RectCharset::simple_lines`].
+    pub fn new() -> Panel {
+        Panel {
+            title: None,
+            charset: RectCharset::simple_lines(),
+            padding: 0,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one with the given `title` on its top border.
+    pub fn title(mut self, title: &str) -> Panel {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Consumes the receiver and returns a new one drawn with the given [`RectCharset`].
+    pub fn charset(mut self, charset: RectCharset) -> Panel {
+        self.charset = charset;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with `padding` empty cells between the border
+    /// and the inner area.
+    pub fn padding(mut self, padding: i32) -> Panel {
+        self.padding = padding.max(0);
+        self
+    }
+
+    /// Returns the area inside the border and the padding of a panel drawn into `rect`.
+    pub fn inner(&self, rect: Rect) -> Rect {
+        rect.shrink(1 + self.padding)
+    }
+}
+
+impl Default for Panel {
+    fn default() -> Self {
+        Panel::new()
+    }
+}
+
+impl Widget for Panel {
+    fn measure(&self) -> Vec2 {
+        let title = self.title.as_ref().map_or(0, |t| t.chars().count() + 4);
+        let border = 2 + 2 * self.padding;
+        Vec2::xy((title as i32).max(border), border)
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let foreground = *pencil.foreground();
+        let theme = *pencil.theme();
+        pencil
+            .set_foreground(theme.border)
+            .draw_rect(&self.charset, rect.position, rect.dimension);
+        if let Some(title) = &self.title {
+            let title = truncate(&format!(" {} ", title), rect.dimension.x - 4);
+            pencil
+                .set_foreground(theme.accent)
+                .draw_text(&title, rect.position + Vec2::x(2));
+        }
+        pencil.set_foreground(foreground);
+    }
+}
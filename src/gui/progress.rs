@@ -0,0 +1,412 @@
+//! Progress feedback: progress bars, gauges and spinners.
+
+use std::time::Duration;
+
+use super::widget::truncate;
+use super::Widget;
+use crate::app::State;
+use crate::drawing::{Pencil, HORIZONTAL_EIGHTHS};
+use crate::spatial::{Rect, Vec2};
+use crate::terminal::Color;
+
+/// Returns the fraction of the `period` elapsed at `elapsed`, from `0.0` to `1.0`.
+fn phase(elapsed: Duration, period: Duration) -> f32 {
+    match period.as_secs_f32() {
+        period if period > 0.0 => (elapsed.as_secs_f32() % period) / period,
+        _ => 0.0,
+    }
+}
+
+/// Returns `elapsed` reduced modulo `period`, so that the time of a looping animation stays
+/// bounded.
+fn wrap(elapsed: Duration, period: Duration) -> Duration {
+    match period.as_nanos() {
+        0 => Duration::ZERO,
+        period => Duration::from_nanos((elapsed.as_nanos() % period) as u64),
+    }
+}
+
+/// A [`Widget`] that shows how much of a task is done as a horizontal bar, with a precision of
+/// an eighth of a cell, or, if it is indeterminate, a block bouncing from side to side.
+///
+/// The bar fills every line of its rect. Indeterminate bars move with the time given to
+/// [`ProgressBar::advance`], or with [`State::dt`] through [`Widget::update`].
+///
+/// ```rust
+/// # use ruscii::drawing::Pencil;
+/// # use ruscii::gui::{ProgressBar, Widget};
+/// # use ruscii::spatial::{Rect, Vec2};
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let mut bar = ProgressBar::new();
+/// bar.set_progress(0.55);
+///
+/// let mut canvas = Canvas::new(Vec2::xy(4, 1), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_widget(&bar, Rect::from_dimension(Vec2::xy(4, 1)));
+/// let line: String = (0..4).map(|x| canvas.elem(Vec2::x(x)).unwrap().value).collect();
+/// assert_eq!(line, "██▎ ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    progress: f32,
+    indeterminate: bool,
+    elapsed: Duration,
+    period: Duration,
+    color: Option<Color>,
+    track: char,
+}
+
+impl ProgressBar {
+    /// Creates a determinate [`ProgressBar`] with no progress.
+    pub fn new() -> ProgressBar {
+        ProgressBar {
+            progress: 0.0,
+            indeterminate: false,
+            elapsed: Duration::ZERO,
+            period: Duration::from_secs(2),
+            color: None,
+            track: ' ',
+        }
+    }
+
+    /// Creates an indeterminate [`ProgressBar`], for tasks whose progress is unknown.
+    pub fn indeterminate() -> ProgressBar {
+        ProgressBar {
+            indeterminate: true,
+            ..ProgressBar::new()
+        }
+    }
+
+    /// Consumes the receiver and returns a new one whose filled part is drawn with the given
+    /// `color`. Without a color, it takes the primary color of the [`Theme`] of the [`Pencil`].
+    ///
+    /// [`Theme`]: crate::drawing::Theme
+    pub fn color(mut self, color: Color) -> ProgressBar {
+        self.color = Some(color);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose empty part is drawn with the given
+    /// `track` character, such as `░`. The default is a space.
+    pub fn track(mut self, track: char) -> ProgressBar {
+        self.track = track;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one where the block of an indeterminate bar goes
+    /// from one side to the other and back in the given `period`. The default is 2 seconds.
+    pub fn period(mut self, period: Duration) -> ProgressBar {
+        self.period = period;
+        self
+    }
+
+    /// Returns the progress, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Sets the progress, clamped to `[0.0, 1.0]`.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress = progress.clamp(0.0, 1.0);
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        self.indeterminate
+    }
+
+    /// Makes the bar indeterminate or determinate.
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        self.indeterminate = indeterminate;
+    }
+
+    /// Advances the time of the bar by `dt`, moving the block of an indeterminate bar.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn advance(&mut self, dt: Duration) -> &mut ProgressBar {
+        self.elapsed = wrap(self.elapsed + dt, self.period);
+        self
+    }
+
+    /// Returns the characters of a line of the bar with the given `width`.
+    fn line(&self, width: usize) -> Vec<char> {
+        let mut line = vec![self.track; width];
+        if self.indeterminate {
+            let length = (width / 4).max(1).min(width);
+            let travel = (width - length) as f32;
+            let bounce = 1.0 - (2.0 * phase(self.elapsed, self.period) - 1.0).abs();
+            let start = (travel * bounce).round() as usize;
+            for cell in line.iter_mut().skip(start).take(length) {
+                *cell = '█';
+            }
+        } else {
+            let eighths = (self.progress * width as f32 * 8.0).round() as usize;
+            let (full, part) = (eighths / 8, eighths % 8);
+            for cell in line.iter_mut().take(full) {
+                *cell = '█';
+            }
+            if part > 0 && full < width {
+                line[full] = HORIZONTAL_EIGHTHS[part];
+            }
+        }
+        line
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        ProgressBar::new()
+    }
+}
+
+impl Widget for ProgressBar {
+    fn measure(&self) -> Vec2 {
+        Vec2::xy(20, 1)
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let line: String = self
+            .line(rect.dimension.x.max(0) as usize)
+            .into_iter()
+            .collect();
+        let foreground = *pencil.foreground();
+        pencil.set_foreground(self.color.unwrap_or(pencil.theme().primary));
+        for y in 0..rect.dimension.y {
+            pencil.draw_text(&line, rect.position + Vec2::y(y));
+        }
+        pencil.set_foreground(foreground);
+    }
+
+    /// Advances the time of the bar by [`State::dt`].
+    fn update(&mut self, state: &State) {
+        self.advance(*state.dt());
+    }
+}
+
+/// A [`Widget`] that shows a labelled [`ProgressBar`] in a line: the label on the left, the bar,
+/// and the percentage, or a custom text, on the right.
+///
+/// ```rust
+/// # use ruscii::drawing::Pencil;
+/// # use ruscii::gui::{Gauge, Widget};
+/// # use ruscii::spatial::{Rect, Vec2};
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let mut gauge = Gauge::new("HP");
+/// gauge.set_progress(0.5);
+///
+/// let mut canvas = Canvas::new(Vec2::xy(16, 1), &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_widget(&gauge, Rect::from_dimension(Vec2::xy(16, 1)));
+/// let line: String = (0..16).map(|x| canvas.elem(Vec2::x(x)).unwrap().value).collect();
+/// assert_eq!(line, "HP ████      50%");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gauge {
+    label: String,
+    text: Option<String>,
+    bar: ProgressBar,
+}
+
+impl Gauge {
+    /// Creates a [`Gauge`] with the given `label` and no progress.
+    pub fn new(label: &str) -> Gauge {
+        Gauge {
+            label: label.to_string(),
+            text: None,
+            bar: ProgressBar::new(),
+        }
+    }
+
+    /// Consumes the receiver and returns a new one drawn with the given [`ProgressBar`], which
+    /// sets its colors and track.
+    pub fn bar(mut self, bar: ProgressBar) -> Gauge {
+        self.bar = bar;
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    /// Sets the text shown on the right, such as `30/100`, or the percentage with [`None`].
+    pub fn set_text(&mut self, text: Option<&str>) {
+        self.text = text.map(|text| text.to_string());
+    }
+
+    /// Returns the progress, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        self.bar.progress()
+    }
+
+    /// Sets the progress, clamped to `[0.0, 1.0]`.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.bar.set_progress(progress);
+    }
+
+    fn value(&self) -> String {
+        match &self.text {
+            Some(text) => text.clone(),
+            None => format!("{:>3}%", (self.bar.progress() * 100.0).round()),
+        }
+    }
+}
+
+impl Widget for Gauge {
+    fn measure(&self) -> Vec2 {
+        let label = self.label.chars().count();
+        Vec2::xy(label + 1 + 20 + 1 + self.value().chars().count(), 1)
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let label = truncate(&self.label, rect.dimension.x);
+        let value = self.value();
+        let label_width = label.chars().count() as i32;
+        let value_width = value.chars().count() as i32;
+        let bar_width = rect.dimension.x - label_width - value_width - 2;
+
+        pencil.draw_text(&label, rect.position);
+        if bar_width > 0 {
+            let bar = Rect::new(
+                rect.position + Vec2::x(label_width + 1),
+                Vec2::xy(bar_width, 1),
+            );
+            pencil.draw_widget(&self.bar, bar).draw_text(
+                &value,
+                rect.position + Vec2::x(rect.dimension.x - value_width),
+            );
+        }
+    }
+}
+
+/// The sets of frames that a [`Spinner`] can cycle through.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SpinnerStyle {
+    /// `|/-\`
+    Line,
+    /// Braille dots going around: `⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`.
+    Dots,
+    /// A quarter circle going around: `◜◝◞◟`.
+    Arc,
+    /// A block going around the quadrants of a cell: `▖▘▝▗`.
+    Quadrants,
+}
+
+impl SpinnerStyle {
+    /// Returns the frames of the style.
+    pub fn frames(&self) -> &'static str {
+        match self {
+            SpinnerStyle::Line => "|/-\\",
+            SpinnerStyle::Dots => "⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏",
+            SpinnerStyle::Arc => "◜◝◞◟",
+            SpinnerStyle::Quadrants => "▖▘▝▗",
+        }
+    }
+}
+
+/// A [`Widget`] that shows that a task is running with a character that cycles through a set of
+/// frames, followed by an optional label.
+///
+/// The frames change with the time given to [`Spinner::advance`], or with [`State::dt`] through
+/// [`Widget::update`].
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use ruscii::gui::{Spinner, SpinnerStyle};
+/// #
+/// let mut spinner = Spinner::new(SpinnerStyle::Line).interval(Duration::from_millis(100));
+/// assert_eq!(spinner.frame(), '|');
+/// spinner.advance(Duration::from_millis(250));
+/// assert_eq!(spinner.frame(), '-');
+/// ```
+#[derive(Debug, Clone)]
+pub struct Spinner {
+    frames: Vec<char>,
+    interval: Duration,
+    elapsed: Duration,
+    label: String,
+}
+
+impl Spinner {
+    /// Creates a [`Spinner`] with the frames of the given [`SpinnerStyle`] and a frame every
+    /// 100 milliseconds.
+    pub fn new(style: SpinnerStyle) -> Spinner {
+        Spinner::from_frames(style.frames())
+    }
+
+    /// Creates a [`Spinner`] that cycles through the characters of `frames`. Empty `frames` are
+    /// taken as a space.
+    pub fn from_frames(frames: &str) -> Spinner {
+        let mut frames: Vec<char> = frames.chars().collect();
+        if frames.is_empty() {
+            frames.push(' ');
+        }
+        Spinner {
+            frames,
+            interval: Duration::from_millis(100),
+            elapsed: Duration::ZERO,
+            label: String::new(),
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that shows each frame for `interval`.
+    pub fn interval(mut self, interval: Duration) -> Spinner {
+        self.interval = interval;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with the given `label` after the frame.
+    pub fn label(mut self, label: &str) -> Spinner {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    /// Returns the current frame.
+    pub fn frame(&self) -> char {
+        let cycle = self.interval * self.frames.len() as u32;
+        let index = (phase(self.elapsed, cycle) * self.frames.len() as f32) as usize;
+        self.frames[index.min(self.frames.len() - 1)]
+    }
+
+    /// Advances the time of the spinner by `dt`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn advance(&mut self, dt: Duration) -> &mut Spinner {
+        let cycle = self.interval * self.frames.len() as u32;
+        self.elapsed = wrap(self.elapsed + dt, cycle);
+        self
+    }
+}
+
+impl Widget for Spinner {
+    fn measure(&self) -> Vec2 {
+        match self.label.is_empty() {
+            true => Vec2::xy(1, 1),
+            false => Vec2::xy(self.label.chars().count() + 2, 1),
+        }
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        if rect.dimension.x <= 0 {
+            return;
+        }
+        let foreground = *pencil.foreground();
+        let label = truncate(&self.label, rect.dimension.x - 2);
+        pencil
+            .set_foreground(pencil.theme().accent)
+            .draw_char(self.frame(), rect.position)
+            .set_foreground(foreground)
+            .draw_text(&label, rect.position + Vec2::x(2));
+    }
+
+    /// Advances the time of the spinner by [`State::dt`].
+    fn update(&mut self, state: &State) {
+        self.advance(*state.dt());
+    }
+}
@@ -0,0 +1,287 @@
+//! Viewports over content larger than their rect.
+
+use std::cell::Cell;
+
+use super::widget::draw_scrollbar;
+use super::Widget;
+use crate::drawing::Pencil;
+use crate::keyboard::{Key, KeyEvent};
+use crate::spatial::{Rect, Vec2};
+use crate::terminal::Canvas;
+
+/// The number of lines scrolled by each step of the mouse wheel.
+const WHEEL_LINES: i32 = 3;
+
+enum Content {
+    Lines(Vec<String>),
+    Canvas(Canvas),
+}
+
+/// A focusable [`Widget`] that shows the part of a content larger than its rect, such as help
+/// texts, inventories or logs. The content is a list of lines or an offscreen [`Canvas`].
+///
+/// While focused, the arrow keys scroll by one line or column, `PageUp` and `PageDown` by the
+/// lines visible, and `Home` and `End` go to the top or the bottom. Keys that do not move the
+/// view, such as arrows at its edges, are not used, so a [`FocusManager`](super::FocusManager)
+/// can move the focus with them. [`Widget::handle_wheel`] scrolls with the mouse wheel, if the
+/// mouse is captured with [`Config::mouse`](crate::app::Config::mouse). Scrollbars are shown on the right and bottom
+/// sides when the content does not fit.
+///
+/// With [`ScrollView::stick_to_bottom`], the view follows the new lines added to the bottom of
+/// the content while it is scrolled to the bottom, as a log does.
+///
+/// ```rust
+/// # use ruscii::drawing::Pencil;
+/// # use ruscii::gui::{ScrollView, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// # use ruscii::spatial::{Rect, Vec2};
+/// # use ruscii::terminal::{Canvas, VisualElement};
+/// #
+/// let mut log = ScrollView::new().stick_to_bottom(true);
+/// for i in 1..=5 {
+///     log.push_line(&format!("event {}", i));
+/// }
+///
+/// let rect = Rect::from_dimension(Vec2::xy(10, 3));
+/// let mut canvas = Canvas::new(rect.dimension, &VisualElement::default());
+/// Pencil::new(&mut canvas).draw_widget(&log, rect);
+/// assert_eq!(log.offset(), Vec2::xy(0, 2));
+/// assert!(!log.handle_event(&KeyEvent::Pressed(Key::Down)));
+///
+/// assert!(log.handle_event(&KeyEvent::Pressed(Key::Up)));
+/// log.push_line("event 6");
+/// Pencil::new(&mut canvas).draw_widget(&log, rect);
+/// assert_eq!(log.offset(), Vec2::xy(0, 1));
+///
+/// assert!(log.handle_wheel(-1));
+/// assert_eq!(log.offset(), Vec2::zero());
+/// ```
+pub struct ScrollView {
+    content: Content,
+    offset: Cell<Vec2>,
+    viewport: Cell<Vec2>,
+    following: Cell<bool>,
+    stick_to_bottom: bool,
+    scrollbars: bool,
+    focused: bool,
+}
+
+impl ScrollView {
+    /// Creates a [`ScrollView`] without lines.
+    pub fn new() -> ScrollView {
+        ScrollView {
+            content: Content::Lines(Vec::new()),
+            offset: Cell::new(Vec2::zero()),
+            viewport: Cell::new(Vec2::zero()),
+            following: Cell::new(false),
+            stick_to_bottom: false,
+            scrollbars: true,
+            focused: false,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that keeps showing the bottom of the content
+    /// while it is scrolled to the bottom, or not. Disabled by default.
+    pub fn stick_to_bottom(mut self, enable: bool) -> ScrollView {
+        self.stick_to_bottom = enable;
+        self.following.set(enable);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that shows scrollbars when the content does
+    /// not fit, or not. Enabled by default.
+    pub fn scrollbars(mut self, enable: bool) -> ScrollView {
+        self.scrollbars = enable;
+        self
+    }
+
+    /// Replaces the content with the given `lines`.
+    pub fn set_lines(&mut self, lines: &[&str]) {
+        let lines = lines.iter().map(|line| line.to_string()).collect();
+        self.content = Content::Lines(lines);
+    }
+
+    /// Adds a line to the bottom of the content. If the content is a [`Canvas`], it is replaced.
+    pub fn push_line(&mut self, line: &str) {
+        match &mut self.content {
+            Content::Lines(lines) => lines.push(line.to_string()),
+            Content::Canvas(_) => self.content = Content::Lines(vec![line.to_string()]),
+        }
+    }
+
+    /// Replaces the content with the given `canvas`, drawn with its colors.
+    pub fn set_canvas(&mut self, canvas: Canvas) {
+        self.content = Content::Canvas(canvas);
+    }
+
+    /// Returns the offscreen [`Canvas`] of the content, if it is one, to draw into it.
+    pub fn canvas_mut(&mut self) -> Option<&mut Canvas> {
+        match &mut self.content {
+            Content::Canvas(canvas) => Some(canvas),
+            Content::Lines(_) => None,
+        }
+    }
+
+    /// Removes all the content and scrolls back to the top.
+    pub fn clear(&mut self) {
+        self.content = Content::Lines(Vec::new());
+        self.offset.set(Vec2::zero());
+    }
+
+    /// Returns the dimension of the content.
+    pub fn content_dimension(&self) -> Vec2 {
+        match &self.content {
+            Content::Lines(lines) => {
+                let width = lines.iter().map(|line| line.chars().count()).max();
+                Vec2::xy(width.unwrap_or(0), lines.len())
+            }
+            Content::Canvas(canvas) => canvas.dimension(),
+        }
+    }
+
+    /// Returns the position of the content shown at the top-left corner of the view.
+    pub fn offset(&self) -> Vec2 {
+        self.offset.get()
+    }
+
+    /// Scrolls to show the content at `offset` at the top-left corner of the view. The offset
+    /// is clamped to the content when the view is drawn.
+    pub fn scroll_to(&mut self, offset: Vec2) {
+        let offset = Vec2::xy(offset.x.max(0), offset.y.max(0));
+        self.offset.set(offset);
+        self.following
+            .set(self.stick_to_bottom && offset.y >= self.max_offset(self.viewport.get()).y);
+    }
+
+    /// Scrolls by the given `delta`, positive rightwards and downwards.
+    pub fn scroll_by(&mut self, delta: Vec2) {
+        self.scroll_to(self.offset() + delta);
+    }
+
+    /// Checks if the view shows the bottom of the content.
+    pub fn is_at_bottom(&self) -> bool {
+        self.offset().y >= self.max_offset(self.viewport.get()).y
+    }
+
+    fn max_offset(&self, viewport: Vec2) -> Vec2 {
+        let content = self.content_dimension();
+        Vec2::xy(
+            (content.x - viewport.x).max(0),
+            (content.y - viewport.y).max(0),
+        )
+    }
+
+    /// Returns the dimension of the view inside the given `dimension`, and whether it has a
+    /// vertical and a horizontal scrollbar.
+    fn layout(&self, dimension: Vec2) -> (Vec2, bool, bool) {
+        let content = self.content_dimension();
+        if !self.scrollbars {
+            return (dimension, false, false);
+        }
+        let mut vertical = content.y > dimension.y;
+        let horizontal = content.x > dimension.x - vertical as i32;
+        vertical = content.y > dimension.y - horizontal as i32;
+        let viewport = dimension - Vec2::xy(vertical as i32, horizontal as i32);
+        (viewport, vertical, horizontal)
+    }
+}
+
+impl Default for ScrollView {
+    fn default() -> Self {
+        ScrollView::new()
+    }
+}
+
+impl Widget for ScrollView {
+    fn measure(&self) -> Vec2 {
+        self.content_dimension()
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let (viewport, vertical, horizontal) = self.layout(rect.dimension);
+        if viewport.x <= 0 || viewport.y <= 0 {
+            return;
+        }
+        self.viewport.set(viewport);
+        let max = self.max_offset(viewport);
+        let mut offset = self.offset.get();
+        if self.following.get() {
+            offset.y = max.y;
+        }
+        let offset = Vec2::xy(offset.x.min(max.x), offset.y.min(max.y));
+        self.offset.set(offset);
+
+        match &self.content {
+            Content::Lines(lines) => {
+                let visible = lines
+                    .iter()
+                    .skip(offset.y as usize)
+                    .take(viewport.y as usize);
+                for (row, line) in visible.enumerate() {
+                    let line: String = line
+                        .chars()
+                        .skip(offset.x as usize)
+                        .take(viewport.x as usize)
+                        .collect();
+                    pencil.draw_text(&line, rect.position + Vec2::y(row));
+                }
+            }
+            Content::Canvas(canvas) => {
+                pencil.draw_canvas(&canvas.region(offset, viewport), rect.position);
+            }
+        }
+
+        let content = self.content_dimension();
+        if vertical {
+            let position = rect.position + Vec2::x(viewport.x);
+            let (total, visible) = (content.y as usize, viewport.y as usize);
+            let start = offset.y as usize;
+            draw_scrollbar(pencil, position, visible, total, visible, start, true);
+        }
+        if horizontal {
+            let position = rect.position + Vec2::y(viewport.y);
+            let (total, visible) = (content.x as usize, viewport.x as usize);
+            let start = offset.x as usize;
+            draw_scrollbar(pencil, position, visible, total, visible, start, false);
+        }
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        let page = self.viewport.get().y.max(1);
+        let max = self.max_offset(self.viewport.get());
+        let previous = self.offset();
+        let offset = match event {
+            KeyEvent::Pressed(Key::Up) => previous - Vec2::y(1),
+            KeyEvent::Pressed(Key::Down) => previous + Vec2::y(1),
+            KeyEvent::Pressed(Key::Left) => previous - Vec2::x(1),
+            KeyEvent::Pressed(Key::Right) => previous + Vec2::x(1),
+            KeyEvent::Pressed(Key::PageUp) => previous - Vec2::y(page),
+            KeyEvent::Pressed(Key::PageDown) => previous + Vec2::y(page),
+            KeyEvent::Pressed(Key::Home) => Vec2::zero(),
+            KeyEvent::Pressed(Key::End) => Vec2::xy(previous.x, max.y),
+            _ => return false,
+        };
+        self.scroll_to(Vec2::xy(offset.x.min(max.x), offset.y.min(max.y)));
+        self.offset() != previous
+    }
+
+    fn handle_wheel(&mut self, delta: i32) -> bool {
+        let max = self.max_offset(self.viewport.get());
+        let previous = self.offset();
+        let offset = previous + Vec2::y(delta * WHEEL_LINES);
+        self.scroll_to(Vec2::xy(offset.x, offset.y.min(max.y)));
+        self.offset() != previous
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,635 @@
+//! Editable text: single-line inputs and multi-line areas.
+
+use std::cell::Cell;
+use std::ops::Range;
+
+use super::widget::{with_focus, Modifiers};
+use super::Widget;
+use crate::drawing::Pencil;
+use crate::keyboard::{Key, KeyEvent};
+use crate::spatial::{Rect, Vec2};
+
+/// The editing state shared by [`TextInput`] and [`TextArea`]: the text, the cursor and the
+/// selection, as indices of characters.
+#[derive(Debug, Clone, Default)]
+struct Editor {
+    text: Vec<char>,
+    cursor: usize,
+    anchor: Option<usize>,
+    max_length: Option<usize>,
+    modifiers: Modifiers,
+}
+
+impl Editor {
+    fn text(&self) -> String {
+        self.text.iter().collect()
+    }
+
+    fn set_text(&mut self, text: &str) {
+        let limit = self.max_length.unwrap_or(usize::MAX);
+        self.text = text.chars().take(limit).collect();
+        self.cursor = self.text.len();
+        self.anchor = None;
+    }
+
+    fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor.filter(|&anchor| anchor != self.cursor)?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    fn select(&mut self, range: Range<usize>) {
+        self.anchor = Some(range.start.min(self.text.len()));
+        self.cursor = range.end.min(self.text.len());
+    }
+
+    /// Moves the cursor to `position`, extending the selection if shift is held.
+    fn move_to(&mut self, position: usize) {
+        if self.modifiers.shift {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position.min(self.text.len());
+    }
+
+    fn remove(&mut self, range: Range<usize>) -> bool {
+        self.anchor = None;
+        if range.is_empty() {
+            return false;
+        }
+        self.cursor = range.start;
+        self.text.drain(range);
+        true
+    }
+
+    fn insert(&mut self, value: char) -> bool {
+        if let Some(selection) = self.selection() {
+            self.remove(selection);
+        }
+        self.anchor = None;
+        if self.max_length.is_some_and(|max| self.text.len() >= max) {
+            return false;
+        }
+        self.text.insert(self.cursor, value);
+        self.cursor += 1;
+        true
+    }
+
+    fn line_start(&self, position: usize) -> usize {
+        let previous = self.text[..position].iter().rposition(|&c| c == '\n');
+        previous.map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self, position: usize) -> usize {
+        let next = self.text[position..].iter().position(|&c| c == '\n');
+        next.map_or(self.text.len(), |i| position + i)
+    }
+
+    fn previous_word(&self, mut position: usize) -> usize {
+        while position > 0 && self.text[position - 1].is_whitespace() {
+            position -= 1;
+        }
+        while position > 0 && !self.text[position - 1].is_whitespace() {
+            position -= 1;
+        }
+        position
+    }
+
+    fn next_word(&self, mut position: usize) -> usize {
+        while position < self.text.len() && !self.text[position].is_whitespace() {
+            position += 1;
+        }
+        while position < self.text.len() && self.text[position].is_whitespace() {
+            position += 1;
+        }
+        position
+    }
+
+    /// Handles the keys common to all the text widgets. Returns `true` if the event was used.
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        if self.modifiers.update(event) {
+            return false;
+        }
+        let key = match event.pressed() {
+            Some(key) => key,
+            None => return false,
+        };
+        let (shift, control) = (self.modifiers.shift, self.modifiers.control);
+        let selection = self.selection();
+        match key {
+            Key::Left => {
+                let target = match selection {
+                    _ if control => self.previous_word(self.cursor),
+                    Some(selection) if !shift => selection.start,
+                    _ => self.cursor.saturating_sub(1),
+                };
+                self.move_to(target);
+            }
+            Key::Right => {
+                let target = match selection {
+                    _ if control => self.next_word(self.cursor),
+                    Some(selection) if !shift => selection.end,
+                    _ => self.cursor + 1,
+                };
+                self.move_to(target);
+            }
+            Key::Home => self.move_to(self.line_start(self.cursor)),
+            Key::End => self.move_to(self.line_end(self.cursor)),
+            Key::Backspace => {
+                let start = match control {
+                    true => self.previous_word(self.cursor),
+                    false => self.cursor.saturating_sub(1),
+                };
+                return self.remove(selection.unwrap_or(start..self.cursor));
+            }
+            Key::Delete => {
+                let end = match control {
+                    true => self.next_word(self.cursor),
+                    false => (self.cursor + 1).min(self.text.len()),
+                };
+                return self.remove(selection.unwrap_or(self.cursor..end));
+            }
+            Key::A if control => self.select(0..self.text.len()),
+            key if !control => match key.to_char(shift) {
+                Some(value) => return self.insert(value),
+                None => return false,
+            },
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Returns the first visible line or column so that `cursor` is within the `size` visible ones,
+/// moving the previous `scroll` as little as possible.
+fn scroll_to(scroll: usize, cursor: usize, size: usize) -> usize {
+    if cursor < scroll {
+        cursor
+    } else if cursor >= scroll + size {
+        (cursor + 1).saturating_sub(size)
+    } else {
+        scroll
+    }
+}
+
+/// Draws the characters of `line`, which starts at the index `start` of the text, from the
+/// column `scroll` on and up to `width` of them, highlighting the ones within `selection`.
+#[allow(clippy::too_many_arguments)]
+fn draw_line(
+    pencil: &mut Pencil,
+    line: &[char],
+    start: usize,
+    scroll: usize,
+    width: usize,
+    position: Vec2,
+    selection: &Option<Range<usize>>,
+    mask: Option<char>,
+) {
+    for (column, &value) in line.iter().enumerate().skip(scroll).take(width) {
+        let selected = selection
+            .as_ref()
+            .is_some_and(|s| s.contains(&(start + column)));
+        with_focus(pencil, selected, |pencil| {
+            pencil.draw_char(mask.unwrap_or(value), position + Vec2::x(column - scroll));
+        });
+    }
+}
+
+/// A focusable single-line [`Widget`] to edit a text, such as a player name or a chat message.
+///
+/// While focused, it shows the terminal cursor at the edit position and handles these keys:
+///
+/// - Printable keys insert their character, replacing the selection.
+/// - `Left` and `Right` move the cursor, by words with `Ctrl`. `Home` and `End` move it to the
+///   start or the end of the text. Moving with `Shift` held extends the selection, and `Ctrl+A`
+///   selects the whole text.
+/// - `Backspace` and `Delete` remove the selection or the character before or after the cursor,
+///   or the word with `Ctrl`.
+/// - `Up` and `Down` go through the history of submitted texts.
+/// - `Enter` submits the text, which is then returned by [`TextInput::submitted`], adds it to the
+///   history and clears the input.
+///
+/// The text scrolls horizontally to keep the cursor visible when it is wider than the input.
+///
+/// ```rust
+/// # use ruscii::gui::{TextInput, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// #
+/// let mut input = TextInput::new().max_length(12);
+/// for key in [Key::LShift, Key::H].iter() {
+///     input.handle_event(&KeyEvent::Pressed(*key));
+/// }
+/// input.handle_event(&KeyEvent::Released(Key::LShift));
+/// input.handle_event(&KeyEvent::Pressed(Key::I));
+/// assert_eq!(input.text(), "Hi");
+///
+/// input.handle_event(&KeyEvent::Pressed(Key::Enter));
+/// assert_eq!(input.submitted(), Some(String::from("Hi")));
+/// assert_eq!(input.text(), "");
+///
+/// input.handle_event(&KeyEvent::Pressed(Key::Up));
+/// assert_eq!(input.text(), "Hi");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    editor: Editor,
+    width: usize,
+    mask: Option<char>,
+    placeholder: String,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    draft: String,
+    submitted: Option<String>,
+    scroll: Cell<usize>,
+    focused: bool,
+}
+
+impl TextInput {
+    /// Creates an empty [`TextInput`] with a width of 20 characters.
+    pub fn new() -> TextInput {
+        TextInput {
+            editor: Editor::default(),
+            width: 20,
+            mask: None,
+            placeholder: String::new(),
+            history: Vec::new(),
+            history_index: None,
+            draft: String::new(),
+            submitted: None,
+            scroll: Cell::new(0),
+            focused: false,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one measured with the given `width`.
+    pub fn width(mut self, width: usize) -> TextInput {
+        self.width = width;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that accepts up to `max_length` characters.
+    pub fn max_length(mut self, max_length: usize) -> TextInput {
+        self.editor.max_length = Some(max_length);
+        let text = self.editor.text();
+        self.editor.set_text(&text);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that shows each character of the text as
+    /// `mask`, such as `*` for passwords.
+    pub fn password(mut self, mask: char) -> TextInput {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that shows the given `placeholder` while the
+    /// text is empty.
+    pub fn placeholder(mut self, placeholder: &str) -> TextInput {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.editor.text()
+    }
+
+    /// Replaces the text, truncated to the maximum length, and moves the cursor to its end.
+    pub fn set_text(&mut self, text: &str) {
+        self.editor.set_text(text);
+    }
+
+    pub fn clear(&mut self) {
+        self.editor.set_text("");
+    }
+
+    /// Returns the index, in characters, of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.editor.cursor
+    }
+
+    /// Moves the cursor to the given character `index` and clears the selection.
+    pub fn set_cursor(&mut self, index: usize) {
+        self.editor.anchor = None;
+        self.editor.cursor = index.min(self.editor.text.len());
+    }
+
+    /// Returns the range of characters selected, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.editor.selection()
+    }
+
+    /// Selects the given `range` of characters, leaving the cursor at its end.
+    pub fn select(&mut self, range: Range<usize>) {
+        self.editor.select(range);
+    }
+
+    /// Returns the texts submitted, from the oldest to the newest.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Adds a `text` to the history, unless it is empty or equal to the newest one.
+    pub fn push_history(&mut self, text: &str) {
+        if !text.is_empty() && self.history.last().map(|last| last.as_str()) != Some(text) {
+            self.history.push(text.to_string());
+        }
+    }
+
+    /// Returns the text submitted with `Enter` since the last call, if any.
+    pub fn submitted(&mut self) -> Option<String> {
+        self.submitted.take()
+    }
+
+    fn browse_history(&mut self, index: Option<usize>) {
+        if self.history_index.is_none() {
+            self.draft = self.editor.text();
+        }
+        self.history_index = index;
+        let text = match index {
+            Some(index) => self.history[index].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.editor.set_text(&text);
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        TextInput::new()
+    }
+}
+
+impl Widget for TextInput {
+    fn measure(&self) -> Vec2 {
+        Vec2::xy(self.width, 1)
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let width = rect.dimension.x.max(0) as usize;
+        if width == 0 || rect.dimension.y <= 0 {
+            return;
+        }
+        if self.editor.text.is_empty() && !self.placeholder.is_empty() {
+            let foreground = *pencil.foreground();
+            let placeholder: String = self.placeholder.chars().take(width).collect();
+            pencil
+                .set_foreground(pencil.theme().disabled)
+                .draw_text(&placeholder, rect.position)
+                .set_foreground(foreground);
+        }
+
+        let scroll = scroll_to(self.scroll.get(), self.editor.cursor, width);
+        self.scroll.set(scroll);
+        let selection = self.editor.selection();
+        let text = &self.editor.text;
+        draw_line(
+            pencil,
+            text,
+            0,
+            scroll,
+            width,
+            rect.position,
+            &selection,
+            self.mask,
+        );
+        if self.focused {
+            pencil.set_cursor(rect.position + Vec2::x(self.editor.cursor - scroll));
+        }
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        let last = self.history.len().checked_sub(1);
+        match event {
+            KeyEvent::Pressed(Key::Up) => match self.history_index {
+                Some(index) => self.browse_history(Some(index.saturating_sub(1))),
+                None if last.is_some() => self.browse_history(last),
+                None => return false,
+            },
+            KeyEvent::Pressed(Key::Down) => match self.history_index {
+                Some(index) if Some(index) < last => self.browse_history(Some(index + 1)),
+                Some(_) => self.browse_history(None),
+                None => return false,
+            },
+            KeyEvent::Pressed(Key::Enter) => {
+                let text = self.editor.text();
+                self.push_history(&text);
+                self.submitted = Some(text);
+                self.history_index = None;
+                self.editor.set_text("");
+            }
+            event => return self.editor.handle_event(event),
+        }
+        true
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.editor.modifiers = Modifiers::default();
+    }
+}
+
+/// A focusable multi-line [`Widget`] to edit a text.
+///
+/// It handles the same keys as a [`TextInput`], where `Home` and `End` move to the start or the
+/// end of the line, except for:
+///
+/// - `Enter`, which inserts a new line.
+/// - `Up`, `Down`, `PageUp` and `PageDown`, which move the cursor to other lines, keeping its
+///   column when possible.
+/// - `Ctrl+Home` and `Ctrl+End`, which move the cursor to the start or the end of the text.
+///
+/// The text scrolls horizontally and vertically to keep the cursor visible.
+///
+/// ```rust
+/// # use ruscii::gui::{TextArea, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// # use ruscii::spatial::Vec2;
+/// #
+/// let mut area = TextArea::new();
+/// area.set_text("first\nsecond line");
+/// assert_eq!(area.cursor(), Vec2::xy(11, 1));
+///
+/// area.handle_event(&KeyEvent::Pressed(Key::Up));
+/// assert_eq!(area.cursor(), Vec2::xy(5, 0));
+/// area.handle_event(&KeyEvent::Pressed(Key::Enter));
+/// assert_eq!(area.text(), "first\n\nsecond line");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextArea {
+    editor: Editor,
+    dimension: Vec2,
+    scroll: Cell<Vec2>,
+    page: Cell<usize>,
+    focused: bool,
+}
+
+impl TextArea {
+    /// Creates an empty [`TextArea`] with a dimension of 40 by 5 characters.
+    pub fn new() -> TextArea {
+        TextArea {
+            editor: Editor::default(),
+            dimension: Vec2::xy(40, 5),
+            scroll: Cell::new(Vec2::zero()),
+            page: Cell::new(5),
+            focused: false,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one measured with the given `dimension`.
+    pub fn dimension(mut self, dimension: Vec2) -> TextArea {
+        self.dimension = dimension;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that accepts up to `max_length` characters,
+    /// counting new lines.
+    pub fn max_length(mut self, max_length: usize) -> TextArea {
+        self.editor.max_length = Some(max_length);
+        let text = self.editor.text();
+        self.editor.set_text(&text);
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.editor.text()
+    }
+
+    /// Replaces the text, truncated to the maximum length, and moves the cursor to its end.
+    pub fn set_text(&mut self, text: &str) {
+        self.editor.set_text(text);
+    }
+
+    pub fn clear(&mut self) {
+        self.editor.set_text("");
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.editor.text.iter().filter(|&&c| c == '\n').count() + 1
+    }
+
+    /// Returns the column and the line of the cursor.
+    pub fn cursor(&self) -> Vec2 {
+        let editor = &self.editor;
+        let start = editor.line_start(editor.cursor);
+        let line = editor.text[..start].iter().filter(|&&c| c == '\n').count();
+        Vec2::xy(editor.cursor - start, line)
+    }
+
+    /// Returns the range of characters selected, if any, counting new lines.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.editor.selection()
+    }
+
+    /// Selects the given `range` of characters, counting new lines, leaving the cursor at its
+    /// end.
+    pub fn select(&mut self, range: Range<usize>) {
+        self.editor.select(range);
+    }
+
+    /// Moves the cursor by the given number of `lines`, upwards if negative. Returns `true` if
+    /// the cursor changed its line.
+    fn move_lines(&mut self, lines: i32) -> bool {
+        let editor = &self.editor;
+        let start = editor.line_start(editor.cursor);
+        let column = editor.cursor - start;
+        let mut target = start;
+        for _ in 0..lines.abs() {
+            if lines < 0 && target > 0 {
+                target = editor.line_start(target - 1);
+            } else if lines > 0 && editor.line_end(target) < editor.text.len() {
+                target = editor.line_end(target) + 1;
+            }
+        }
+        let position = (target + column).min(editor.line_end(target));
+        self.editor.move_to(position);
+        target != start
+    }
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        TextArea::new()
+    }
+}
+
+impl Widget for TextArea {
+    fn measure(&self) -> Vec2 {
+        self.dimension
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let width = rect.dimension.x.max(0) as usize;
+        let height = rect.dimension.y.max(0) as usize;
+        if width == 0 || height == 0 {
+            return;
+        }
+        let cursor = self.cursor();
+        let scroll = self.scroll.get();
+        let scroll = Vec2::xy(
+            scroll_to(scroll.x as usize, cursor.x as usize, width),
+            scroll_to(scroll.y as usize, cursor.y as usize, height),
+        );
+        self.scroll.set(scroll);
+        self.page.set(height);
+
+        let selection = self.editor.selection();
+        let mut start = 0;
+        let lines = self.editor.text.split(|&c| c == '\n');
+        for (row, line) in lines.enumerate() {
+            let visible = row as i32 - scroll.y;
+            if (0..height as i32).contains(&visible) {
+                let position = rect.position + Vec2::y(visible);
+                let scroll_x = scroll.x as usize;
+                draw_line(
+                    pencil, line, start, scroll_x, width, position, &selection, None,
+                );
+            }
+            start += line.len() + 1;
+        }
+        if self.focused {
+            pencil.set_cursor(rect.position + cursor - scroll);
+        }
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        let control = self.editor.modifiers.control;
+        let page = self.page.get().max(1) as i32;
+        match event {
+            KeyEvent::Pressed(Key::Enter) => self.editor.insert('\n'),
+            KeyEvent::Pressed(Key::Up) => self.move_lines(-1),
+            KeyEvent::Pressed(Key::Down) => self.move_lines(1),
+            KeyEvent::Pressed(Key::PageUp) => self.move_lines(-page),
+            KeyEvent::Pressed(Key::PageDown) => self.move_lines(page),
+            KeyEvent::Pressed(Key::Home) if control => {
+                self.editor.move_to(0);
+                true
+            }
+            KeyEvent::Pressed(Key::End) if control => {
+                self.editor.move_to(self.editor.text.len());
+                true
+            }
+            event => self.editor.handle_event(event),
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.editor.modifiers = Modifiers::default();
+    }
+}
@@ -0,0 +1,178 @@
+//! Widgets that hold a choice: checkboxes and radio groups.
+
+use super::widget::{truncate, with_focus};
+use super::Widget;
+use crate::drawing::Pencil;
+use crate::keyboard::{Key, KeyEvent};
+use crate::spatial::{Rect, Vec2};
+
+/// A focusable [`Widget`] with a label and a checked state, toggled with `Enter` or `Space`
+/// while it has the focus.
+#[derive(Debug, Clone)]
+pub struct Checkbox {
+    label: String,
+    checked: bool,
+    focused: bool,
+}
+
+impl Checkbox {
+    /// Creates an unchecked [`Checkbox`] with the given `label`, drawn as `[ ] label`.
+    pub fn new(label: &str) -> Checkbox {
+        Checkbox {
+            label: label.to_string(),
+            checked: false,
+            focused: false,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one with the given checked state.
+    pub fn checked(mut self, checked: bool) -> Checkbox {
+        self.checked = checked;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+}
+
+impl Widget for Checkbox {
+    fn measure(&self) -> Vec2 {
+        Vec2::xy(self.label.chars().count() + 4, 1)
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let mark = if self.checked { 'x' } else { ' ' };
+        let text = truncate(&format!("[{}] {}", mark, self.label), rect.dimension.x);
+        with_focus(pencil, self.focused, |pencil| {
+            pencil.draw_text(&text, rect.position);
+        });
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        match event {
+            KeyEvent::Pressed(Key::Enter) | KeyEvent::Pressed(Key::Space) => {
+                self.checked = !self.checked;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+/// A focusable [`Widget`] with a list of options, one of them selected, drawn one per line. The
+/// arrow keys select the previous or the next option while it has the focus.
+///
+/// ```rust
+/// # use ruscii::gui::{RadioGroup, Widget};
+/// # use ruscii::keyboard::{Key, KeyEvent};
+/// #
+/// let mut difficulty = RadioGroup::new(&["Easy", "Normal", "Hard"]);
+/// difficulty.handle_event(&KeyEvent::Pressed(Key::Down));
+/// assert_eq!(difficulty.selected(), 1);
+/// assert_eq!(difficulty.selected_option(), Some("Normal"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadioGroup {
+    options: Vec<String>,
+    selected: usize,
+    focused: bool,
+}
+
+impl RadioGroup {
+    /// Creates a [`RadioGroup`] with the given `options` and the first one selected.
+    pub fn new(options: &[&str]) -> RadioGroup {
+        RadioGroup {
+            options: options.iter().map(|option| option.to_string()).collect(),
+            selected: 0,
+            focused: false,
+        }
+    }
+
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the selected option, or [`None`] if there are no options.
+    pub fn selected_option(&self) -> Option<&str> {
+        self.options
+            .get(self.selected)
+            .map(|option| option.as_str())
+    }
+
+    /// Selects the option with the given `index`. Indices out of range are ignored.
+    pub fn set_selected(&mut self, index: usize) {
+        if index < self.options.len() {
+            self.selected = index;
+        }
+    }
+}
+
+impl Widget for RadioGroup {
+    fn measure(&self) -> Vec2 {
+        let width = self.options.iter().map(|o| o.chars().count()).max();
+        Vec2::xy(width.map_or(0, |width| width + 4), self.options.len())
+    }
+
+    fn draw(&self, pencil: &mut Pencil, rect: Rect) {
+        let visible = self.options.iter().take(rect.dimension.y.max(0) as usize);
+        for (i, option) in visible.enumerate() {
+            let mark = if i == self.selected { '•' } else { ' ' };
+            let text = truncate(&format!("({}) {}", mark, option), rect.dimension.x);
+            let focused = self.focused && i == self.selected;
+            with_focus(pencil, focused, |pencil| {
+                pencil.draw_text(&text, rect.position + Vec2::y(i));
+            });
+        }
+    }
+
+    fn handle_event(&mut self, event: &KeyEvent) -> bool {
+        let last = self.options.len().saturating_sub(1);
+        match event {
+            KeyEvent::Pressed(Key::Up) | KeyEvent::Pressed(Key::Left) if self.selected > 0 => {
+                self.selected -= 1;
+                true
+            }
+            KeyEvent::Pressed(Key::Down) | KeyEvent::Pressed(Key::Right)
+                if self.selected < last =>
+            {
+                self.selected += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,148 @@
+//! The common interface of all widgets.
+
+use crate::app::State;
+use crate::drawing::Pencil;
+use crate::keyboard::KeyEvent;
+use crate::spatial::{Rect, Vec2};
+
+/// An element of a user interface that can be measured, drawn into a [`Rect`] and, if it is
+/// focusable, controlled with the keyboard.
+///
+/// Widgets keep their own state between frames. Each frame, they receive the key events and the
+/// mouse wheel with [`Widget::update`] and are drawn with [`Pencil::draw_widget`]. Only focused
+/// widgets handle them in [`Widget::update`].
+pub trait Widget {
+    /// Returns the dimension the widget needs to be drawn completely.
+    fn measure(&self) -> Vec2;
+
+    /// Draws the widget into the given `rect`, which can be smaller than [`Widget::measure`].
+    fn draw(&self, pencil: &mut Pencil, rect: Rect);
+
+    /// Handles a key event. Returns `true` if the widget used it.
+    fn handle_event(&mut self, _event: &KeyEvent) -> bool {
+        false
+    }
+
+    /// Handles a movement of the mouse wheel by `delta` steps, positive downwards. Returns `true`
+    /// if the widget used it.
+    fn handle_wheel(&mut self, _delta: i32) -> bool {
+        false
+    }
+
+    /// Returns `true` if the widget can receive the focus, and with it the key events.
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn is_focused(&self) -> bool {
+        false
+    }
+
+    /// Gives or takes the focus to the widget. Widgets that are not focusable ignore it.
+    fn set_focused(&mut self, _focused: bool) {}
+
+    /// Passes the key events of the current frame from the given `state` to
+    /// [`Widget::handle_event`], and the mouse wheel to [`Widget::handle_wheel`], if the widget
+    /// is focused.
+    fn update(&mut self, state: &State) {
+        if self.is_focused() {
+            for event in state.keyboard().last_key_events() {
+                self.handle_event(event);
+            }
+            let wheel = state.keyboard().last_wheel_delta();
+            if wheel != 0 {
+                self.handle_wheel(wheel);
+            }
+        }
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws the given `widget` into the given `rect`.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_widget(&mut self, widget: &dyn Widget, rect: Rect) -> &mut Pencil<'a> {
+        widget.draw(self, rect);
+        self
+    }
+}
+
+/// Calls `f` with the `pencil` set to the selection colors of its [`Theme`] if `focused`, which
+/// is how widgets show that they have the focus or that a part of them is selected.
+///
+/// [`Theme`]: crate::drawing::Theme
+pub(super) fn with_focus(pencil: &mut Pencil, focused: bool, f: impl FnOnce(&mut Pencil)) {
+    let (foreground, background) = (*pencil.foreground(), *pencil.background());
+    if focused {
+        let theme = *pencil.theme();
+        pencil
+            .set_foreground(theme.selection_foreground)
+            .set_background(theme.selection);
+    }
+    f(pencil);
+    pencil.set_foreground(foreground).set_background(background);
+}
+
+/// Returns the first `width` characters of `text`.
+pub(super) fn truncate(text: &str, width: i32) -> String {
+    text.chars().take(width.max(0) as usize).collect()
+}
+
+/// The modifier keys held down, tracked from the key events that a widget receives.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+}
+
+impl Modifiers {
+    /// Updates the modifiers with the given `event`. Returns `true` if it was a modifier key.
+    pub fn update(&mut self, event: &KeyEvent) -> bool {
+        let (key, down) = match *event {
+            KeyEvent::Pressed(key) => (key, true),
+            KeyEvent::Released(key) => (key, false),
+        };
+        if key.is_shift() {
+            self.shift = down;
+        } else if key.is_control() {
+            self.control = down;
+        } else {
+            return false;
+        }
+        true
+    }
+}
+
+/// Draws a scrollbar of the given `length` at `position`, downwards if `vertical` or rightwards
+/// otherwise, for a content of `total` lines or columns of which `visible` are shown starting
+/// from `offset`.
+pub(super) fn draw_scrollbar(
+    pencil: &mut Pencil,
+    position: Vec2,
+    length: usize,
+    total: usize,
+    visible: usize,
+    offset: usize,
+    vertical: bool,
+) {
+    if length == 0 || total <= visible {
+        return;
+    }
+    let thumb = (length * visible / total).clamp(1, length);
+    let start = (offset * (length - thumb) + (total - visible) / 2) / (total - visible);
+    let (track, step) = match vertical {
+        true => ('│', Vec2::y(1)),
+        false => ('─', Vec2::x(1)),
+    };
+    let foreground = *pencil.foreground();
+    pencil.set_foreground(pencil.theme().border);
+    for i in 0..length {
+        let value = if (start..start + thumb).contains(&i) {
+            '█'
+        } else {
+            track
+        };
+        pencil.draw_char(value, position + step * i);
+    }
+    pencil.set_foreground(foreground);
+}
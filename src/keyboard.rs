@@ -157,6 +157,14 @@ pub enum Key {
     F11,
     F12,
 
+    LShift,
+    RShift,
+    LControl,
+    RControl,
+    LAlt,
+    RAlt,
+    Meta,
+
     // The following keys names represent the position of the key in a US keyboard, can vary in others keyboards.
     // Some keys may generate only one event by two key press depending the keyboard distribution.
     Grave,
@@ -174,12 +182,120 @@ pub enum Key {
     Unknown,
 }
 
-/* TODO: Add modifiers to events.
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-pub enum Modifier {
-    LControl, RControl, LShift, RShift, LAlt, RAlt, Meta,
+impl Key {
+    /// Checks if the [`Key`] is one of the shift keys.
+    pub fn is_shift(self) -> bool {
+        matches!(self, Key::LShift | Key::RShift)
+    }
+
+    /// Checks if the [`Key`] is one of the control keys.
+    pub fn is_control(self) -> bool {
+        matches!(self, Key::LControl | Key::RControl)
+    }
+
+    /// Checks if the [`Key`] is one of the alt keys.
+    pub fn is_alt(self) -> bool {
+        matches!(self, Key::LAlt | Key::RAlt)
+    }
+
+    /// Returns the character that the [`Key`] types in a U.S. keyboard layout, with or without
+    /// `shift` held, or [`None`] if it does not type any.
+    ///
+    /// ```rust
+    /// # use ruscii::keyboard::Key;
+    /// #
+    /// assert_eq!(Key::A.to_char(false), Some('a'));
+    /// assert_eq!(Key::A.to_char(true), Some('A'));
+    /// assert_eq!(Key::Num1.to_char(true), Some('!'));
+    /// assert_eq!(Key::Enter.to_char(false), None);
+    /// ```
+    pub fn to_char(self, shift: bool) -> Option<char> {
+        const LETTERS: [Key; 26] = [
+            Key::A,
+            Key::B,
+            Key::C,
+            Key::D,
+            Key::E,
+            Key::F,
+            Key::G,
+            Key::H,
+            Key::I,
+            Key::J,
+            Key::K,
+            Key::L,
+            Key::M,
+            Key::N,
+            Key::O,
+            Key::P,
+            Key::Q,
+            Key::R,
+            Key::S,
+            Key::T,
+            Key::U,
+            Key::V,
+            Key::W,
+            Key::X,
+            Key::Y,
+            Key::Z,
+        ];
+        const DIGITS: [Key; 10] = [
+            Key::Num0,
+            Key::Num1,
+            Key::Num2,
+            Key::Num3,
+            Key::Num4,
+            Key::Num5,
+            Key::Num6,
+            Key::Num7,
+            Key::Num8,
+            Key::Num9,
+        ];
+        const NUMPAD: [Key; 10] = [
+            Key::Numpad0,
+            Key::Numpad1,
+            Key::Numpad2,
+            Key::Numpad3,
+            Key::Numpad4,
+            Key::Numpad5,
+            Key::Numpad6,
+            Key::Numpad7,
+            Key::Numpad8,
+            Key::Numpad9,
+        ];
+
+        if let Some(i) = LETTERS.iter().position(|&key| key == self) {
+            let value = (b'a' + i as u8) as char;
+            return Some(if shift {
+                value.to_ascii_uppercase()
+            } else {
+                value
+            });
+        }
+        if let Some(i) = DIGITS.iter().position(|&key| key == self) {
+            let chars = if shift { ")!@#$%^&*(" } else { "0123456789" };
+            return chars.chars().nth(i);
+        }
+        if let Some(i) = NUMPAD.iter().position(|&key| key == self) {
+            return "0123456789".chars().nth(i);
+        }
+        let (value, shifted) = match self {
+            Key::Space => (' ', ' '),
+            Key::Grave => ('`', '~'),
+            Key::Minus => ('-', '_'),
+            Key::Equal => ('=', '+'),
+            Key::LeftBracket => ('[', '{'),
+            Key::RightBracket => (']', '}'),
+            Key::BackSlash => ('\\', '|'),
+            Key::Semicolon => (';', ':'),
+            Key::Apostrophe => ('\'', '"'),
+            Key::Comma => (',', '<'),
+            Key::Dot => ('.', '>'),
+            Key::Slash => ('/', '?'),
+            _ => return None,
+        };
+        Some(if shift { shifted } else { value })
+    }
 }
-*/
 
 /// Events that are detected for each key.
 ///
@@ -219,9 +335,11 @@ pub struct Keyboard {
     acc_thread: Option<JoinHandle<()>>,
     event_thread: Option<JoinHandle<()>>,
     event_receiver: Receiver<KeyEvent>,
+    wheel_receiver: Receiver<i32>,
     state: HashMap<Key, usize>,
     last_key_events: Vec<KeyEvent>,
     last_key_stamp: usize,
+    last_wheel_delta: i32,
 }
 
 impl Default for Keyboard {
@@ -231,6 +349,7 @@ impl Default for Keyboard {
         let (acc_sender, acc_receiver): (Sender<KeyEvent>, Receiver<KeyEvent>) = mpsc::channel();
         let (event_sender, event_receiver): (Sender<KeyEvent>, Receiver<KeyEvent>) =
             mpsc::channel();
+        let (wheel_sender, wheel_receiver): (Sender<i32>, Receiver<i32>) = mpsc::channel();
 
         let acc_thread_running = thread_running.clone();
         let pressed_event_sender = event_sender.clone();
@@ -240,7 +359,7 @@ impl Default for Keyboard {
                 .checked_sub(Duration::from_millis(KEY_EVENT_FOCUS_DELAY_MS + 1))
                 .unwrap();
             while acc_thread_running.load(Ordering::SeqCst) {
-                if let Some(timestamp) = Self::process_input_timestamp() {
+                if let Some(timestamp) = Self::process_input_timestamp(&wheel_sender) {
                     last_input_timestamp = timestamp;
                 }
 
@@ -297,9 +416,11 @@ impl Default for Keyboard {
             event_thread: Some(event_thread),
             acc_thread: Some(acc_thread),
             event_receiver,
+            wheel_receiver,
             state: HashMap::new(),
             last_key_events: Vec::new(),
             last_key_stamp: 0,
+            last_wheel_delta: 0,
         }
     }
 }
@@ -317,11 +438,21 @@ impl Keyboard {
         keys.into_iter().map(|x| *x.0).collect()
     }
 
+    /// Returns the number of steps that the mouse wheel scrolled during the previous frame,
+    /// positive downwards.
+    ///
+    /// The mouse is only read if it is captured by the [`Window`](crate::terminal::Window), which
+    /// can be enabled with [`Config::mouse`](crate::app::Config::mouse).
+    pub fn last_wheel_delta(&self) -> i32 {
+        self.last_wheel_delta
+    }
+
     /// Clears the [`KeyEvent`]s from the last frame and consumes new ones from the event
     /// [`Receiver`].
     pub fn consume_key_events(&mut self) -> &Vec<KeyEvent> {
         self.last_key_events.clear();
         let events = self.event_receiver.try_iter().collect::<Vec<_>>();
+        self.last_wheel_delta = self.wheel_receiver.try_iter().sum();
 
         for event in &events {
             if let KeyEvent::Pressed(key) = *event {
@@ -344,12 +475,18 @@ impl Keyboard {
         &self.last_key_events
     }
 
-    fn process_input_timestamp() -> Option<Instant> {
+    fn process_input_timestamp(wheel_sender: &Sender<i32>) -> Option<Instant> {
         let mut input_received = false;
         while ct::event::poll(Duration::from_millis(0)).unwrap() {
-            //means: has the app the focus?
-            ct::event::read().unwrap();
-            input_received = true;
+            match ct::event::read().unwrap() {
+                ct::event::Event::Mouse(mouse_event) => match mouse_event.kind {
+                    ct::event::MouseEventKind::ScrollUp => wheel_sender.send(-1).unwrap(),
+                    ct::event::MouseEventKind::ScrollDown => wheel_sender.send(1).unwrap(),
+                    _ => (),
+                },
+                //means: has the app the focus?
+                _ => input_received = true,
+            }
         }
 
         if input_received {
@@ -482,6 +619,14 @@ impl Keyboard {
             dq::Keycode::F11 => Key::F11,
             dq::Keycode::F12 => Key::F12,
 
+            dq::Keycode::LShift => Key::LShift,
+            dq::Keycode::RShift => Key::RShift,
+            dq::Keycode::LControl => Key::LControl,
+            dq::Keycode::RControl => Key::RControl,
+            dq::Keycode::LAlt => Key::LAlt,
+            dq::Keycode::RAlt => Key::RAlt,
+            dq::Keycode::Meta => Key::Meta,
+
             dq::Keycode::Grave => Key::Grave,
             dq::Keycode::Minus => Key::Minus,
             dq::Keycode::Equal => Key::Equal,
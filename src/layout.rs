@@ -0,0 +1,294 @@
+//! # Layout
+//!
+//! The `layout` module splits a [`Rect`] of the screen into smaller ones following a list of
+//! [`Constraint`]s, so the positions and dimensions of the parts of the interface do not need to
+//! be computed from the window size by hand.
+//!
+//! A [`Layout`] splits a rect into rows or columns, and a [`Grid`] splits it into both at once.
+//! Both can leave a margin around the rect, a gap between the parts and a padding inside each
+//! one. The resulting rects can be passed to
+//! [`Pencil::draw_widget`](crate::drawing::Pencil::draw_widget) or used with
+//! [`Pencil::set_origin`](crate::drawing::Pencil::set_origin).
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use ruscii::layout::{Constraint, Layout};
+//! # use ruscii::spatial::{Rect, Vec2};
+//! #
+//! let screen = Rect::from_dimension(Vec2::xy(80, 24));
+//! let rows = Layout::rows(&[Constraint::Fixed(1), Constraint::Fill(1), Constraint::Fixed(1)])
+//!     .split(screen);
+//! assert_eq!(rows[1], Rect::new(Vec2::xy(0, 1), Vec2::xy(80, 22)));
+//!
+//! let columns = Layout::columns(&[Constraint::Percentage(25), Constraint::Fill(1)])
+//!     .gap(1)
+//!     .split(rows[1]);
+//! assert_eq!(columns[0].dimension, Vec2::xy(19, 22));
+//! assert_eq!(columns[1], Rect::new(Vec2::xy(20, 1), Vec2::xy(60, 22)));
+//! ```
+
+use crate::spatial::{Rect, Vec2};
+
+/// The size that a part of a [`Layout`] or a [`Grid`] takes along the direction of the split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// Exactly the given number of cells.
+    Fixed(i32),
+    /// The given percentage of the available space.
+    Percentage(u16),
+    /// The fraction `numerator / denominator` of the available space.
+    Ratio(u32, u32),
+    /// At least the given number of cells, growing like a [`Constraint::Fill`] of weight 1.
+    Min(i32),
+    /// At most the given number of cells, growing like a [`Constraint::Fill`] of weight 1.
+    Max(i32),
+    /// A share of the space left by the other constraints, proportional to the given weight.
+    Fill(u16),
+}
+
+impl Constraint {
+    fn weight(&self) -> i32 {
+        match *self {
+            Constraint::Fill(weight) => weight as i32,
+            Constraint::Min(_) | Constraint::Max(_) => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Returns the sizes of the parts with the given `constraints` in the given `length`.
+///
+/// The fixed, percentage, ratio and min sizes are taken first, and the space left is shared by
+/// weight among the growing constraints. If the sizes do not fit, the last parts are shrunk.
+fn solve(constraints: &[Constraint], length: i32) -> Vec<i32> {
+    let length = length.max(0);
+    let mut sizes: Vec<i32> = constraints
+        .iter()
+        .map(|constraint| match *constraint {
+            Constraint::Fixed(size) | Constraint::Min(size) => size.max(0),
+            Constraint::Percentage(percentage) => length * percentage as i32 / 100,
+            Constraint::Ratio(_, 0) => 0,
+            Constraint::Ratio(numerator, denominator) => {
+                (length as i64 * numerator as i64 / denominator as i64) as i32
+            }
+            Constraint::Max(_) | Constraint::Fill(_) => 0,
+        })
+        .collect();
+
+    let mut growing: Vec<usize> = (0..constraints.len())
+        .filter(|&i| constraints[i].weight() > 0)
+        .collect();
+    let mut remaining = length - sizes.iter().sum::<i32>();
+    while remaining > 0 && !growing.is_empty() {
+        let total: i32 = growing.iter().map(|&i| constraints[i].weight()).sum();
+        let shares: Vec<i32> = growing
+            .iter()
+            .map(|&i| remaining * constraints[i].weight() / total)
+            .collect();
+        let mut leftover = remaining - shares.iter().sum::<i32>();
+        let mut given = 0;
+        for (&i, mut share) in growing.iter().zip(shares) {
+            if leftover > 0 {
+                share += 1;
+                leftover -= 1;
+            }
+            if let Constraint::Max(max) = constraints[i] {
+                share = share.min(max - sizes[i]).max(0);
+            }
+            sizes[i] += share;
+            given += share;
+        }
+        if given == 0 {
+            break;
+        }
+        remaining -= given;
+        growing.retain(|&i| !matches!(constraints[i], Constraint::Max(max) if sizes[i] >= max));
+    }
+
+    let mut excess = sizes.iter().sum::<i32>() - length;
+    for size in sizes.iter_mut().rev() {
+        let cut = (*size).min(excess.max(0));
+        *size -= cut;
+        excess -= cut;
+    }
+    sizes
+}
+
+/// Returns the offsets along the split of the parts with the given `sizes`.
+fn offsets(sizes: &[i32], gap: i32) -> Vec<i32> {
+    sizes
+        .iter()
+        .scan(0, |offset, size| {
+            let current = *offset;
+            *offset += size + gap;
+            Some(current)
+        })
+        .collect()
+}
+
+/// Splits a [`Rect`] into rows or columns following a list of [`Constraint`]s.
+///
+/// The rect is first shrunk by the margin, then split into parts separated by the gap, and each
+/// part is finally shrunk by the padding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    constraints: Vec<Constraint>,
+    vertical: bool,
+    margin: i32,
+    padding: i32,
+    gap: i32,
+}
+
+impl Layout {
+    /// Creates a [`Layout`] that splits a rect into rows, from top to bottom, with the given
+    /// `constraints` on their heights.
+    pub fn rows(constraints: &[Constraint]) -> Layout {
+        Layout {
+            constraints: constraints.to_vec(),
+            vertical: true,
+            margin: 0,
+            padding: 0,
+            gap: 0,
+        }
+    }
+
+    /// Creates a [`Layout`] that splits a rect into columns, from left to right, with the given
+    /// `constraints` on their widths.
+    pub fn columns(constraints: &[Constraint]) -> Layout {
+        Layout {
+            vertical: false,
+            ..Layout::rows(constraints)
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that leaves `margin` cells around the rect.
+    pub fn margin(mut self, margin: i32) -> Layout {
+        self.margin = margin;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that leaves `padding` cells inside each part.
+    pub fn padding(mut self, padding: i32) -> Layout {
+        self.padding = padding;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that leaves `gap` cells between the parts.
+    pub fn gap(mut self, gap: i32) -> Layout {
+        self.gap = gap;
+        self
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Returns the rects of the parts of the given `rect`, one for each constraint.
+    pub fn split(&self, rect: Rect) -> Vec<Rect> {
+        let area = rect.shrink(self.margin);
+        let gaps = self.gap * (self.constraints.len() as i32 - 1).max(0);
+        let length = match self.vertical {
+            true => area.dimension.y,
+            false => area.dimension.x,
+        };
+        let sizes = solve(&self.constraints, length - gaps);
+        offsets(&sizes, self.gap)
+            .into_iter()
+            .zip(sizes)
+            .map(|(offset, size)| {
+                let part = match self.vertical {
+                    true => Rect::new(
+                        area.position + Vec2::y(offset),
+                        Vec2::xy(area.dimension.x, size),
+                    ),
+                    false => Rect::new(
+                        area.position + Vec2::x(offset),
+                        Vec2::xy(size, area.dimension.y),
+                    ),
+                };
+                part.shrink(self.padding)
+            })
+            .collect()
+    }
+}
+
+/// Splits a [`Rect`] into cells by columns and rows at once, each following a list of
+/// [`Constraint`]s.
+///
+/// The cells are returned indexed by column and then by row, so they can be indexed with a
+/// [`Vec2`]:
+///
+/// ```rust
+/// # use ruscii::layout::{Constraint, Grid};
+/// # use ruscii::spatial::{Rect, Vec2};
+/// #
+/// let grid = Grid::new(&[Constraint::Fill(1); 3], &[Constraint::Fixed(3); 2]).gap(Vec2::xy(2, 1));
+/// let cells = grid.split(Rect::from_dimension(Vec2::xy(34, 10)));
+/// assert_eq!(cells[Vec2::xy(1, 1)], Rect::new(Vec2::xy(12, 4), Vec2::xy(10, 3)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    columns: Vec<Constraint>,
+    rows: Vec<Constraint>,
+    margin: i32,
+    padding: i32,
+    gap: Vec2,
+}
+
+impl Grid {
+    /// Creates a [`Grid`] with the given constraints on the widths of its `columns` and on the
+    /// heights of its `rows`.
+    pub fn new(columns: &[Constraint], rows: &[Constraint]) -> Grid {
+        Grid {
+            columns: columns.to_vec(),
+            rows: rows.to_vec(),
+            margin: 0,
+            padding: 0,
+            gap: Vec2::zero(),
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that leaves `margin` cells around the rect.
+    pub fn margin(mut self, margin: i32) -> Grid {
+        self.margin = margin;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that leaves `padding` cells inside each cell.
+    pub fn padding(mut self, padding: i32) -> Grid {
+        self.padding = padding;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that leaves `gap.x` cells between columns and
+    /// `gap.y` cells between rows.
+    pub fn gap(mut self, gap: Vec2) -> Grid {
+        self.gap = gap;
+        self
+    }
+
+    /// Returns the rects of the cells of the given `rect`, indexed by column and then by row.
+    pub fn split(&self, rect: Rect) -> Vec<Vec<Rect>> {
+        let columns = Layout::columns(&self.columns)
+            .margin(self.margin)
+            .gap(self.gap.x)
+            .split(rect);
+        let rows = Layout::rows(&self.rows)
+            .margin(self.margin)
+            .gap(self.gap.y)
+            .split(rect);
+        columns
+            .iter()
+            .map(|column| {
+                rows.iter()
+                    .map(|row| {
+                        let position = Vec2::xy(column.position.x, row.position.y);
+                        let dimension = Vec2::xy(column.dimension.x, row.dimension.y);
+                        Rect::new(position, dimension).shrink(self.padding)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -14,5 +14,9 @@ pub mod app;
 pub mod drawing;
 pub mod gui;
 pub mod keyboard;
+pub mod layout;
+pub mod particle;
 pub mod spatial;
 pub mod terminal;
+pub mod tilemap;
+pub mod tween;
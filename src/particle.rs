@@ -0,0 +1,312 @@
+//! # Particle
+//!
+//! The `particle` module provides a [`ParticleEmitter`] to create effects made of many short-lived
+//! characters, such as explosions, sparks, smoke or rain.
+//!
+//! ## Example
+//!
+//! An emitter is configured once, fed with the elapsed time of each frame and drawn by a
+//! [`Pencil`]:
+//!
+//! ```rust,no_run
+//! # use std::time::Duration;
+//! # use ruscii::app::{App, State};
+//! # use ruscii::drawing::Pencil;
+//! # use ruscii::particle::ParticleEmitter;
+//! # use ruscii::spatial::Vec2;
+//! # use ruscii::terminal::{Color, Window};
+//! #
+//! let mut app = App::default();
+//! let mut explosions = ParticleEmitter::new(Vec2::zero())
+//!     .speed(4.0, 12.0)
+//!     .lifetime(Duration::from_millis(300), Duration::from_millis(700))
+//!     .glyphs(&['@', '*', '+', '.'])
+//!     .colors(&[Color::Yellow, Color::Red, Color::DarkGrey]);
+//!
+//! app.run(|app_state: &mut State, window: &mut Window| {
+//!     if app_state.step() % 30 == 0 {
+//!         explosions.emit_at(Vec2::xy(20, 10), 30);
+//!     }
+//!     explosions.update(*app_state.dt());
+//!     Pencil::new(window.canvas_mut()).draw_particles(&explosions);
+//! });
+//! ```
+
+use super::drawing::Pencil;
+use super::spatial::Vec2;
+use super::terminal::Color;
+use super::tween::Lerp;
+
+use std::f32::consts::PI;
+use std::time::{Duration, SystemTime};
+
+/// A single particle, with a position and a velocity in cells and cells per second.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Particle {
+    pub x: f32,
+    pub y: f32,
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    pub age: Duration,
+    pub lifetime: Duration,
+}
+
+impl Particle {
+    /// Returns the cell where the particle is.
+    pub fn position(&self) -> Vec2 {
+        Vec2::xy(self.x.round() as i32, self.y.round() as i32)
+    }
+
+    /// Returns how much of its lifetime the particle has lived, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.lifetime.is_zero() {
+            return 1.0;
+        }
+        (self.age.as_secs_f32() / self.lifetime.as_secs_f32()).min(1.0)
+    }
+}
+
+/// A small xorshift generator, enough to scatter particles without extra dependencies.
+#[derive(Debug, Clone)]
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next()
+    }
+}
+
+/// An object that creates, moves and ages [`Particle`]s.
+///
+/// Particles are emitted continuously at a rate, with [`ParticleEmitter::rate`], or in bursts,
+/// with [`ParticleEmitter::emit`]. Each one gets a random speed, direction and lifetime within the
+/// configured ranges and is pulled by the gravity of the emitter.
+///
+/// While it lives, a particle is drawn with the glyph and color that correspond to its age: the
+/// glyphs and colors given to the emitter are spread along its lifetime, and the colors are
+/// interpolated between them.
+#[derive(Debug, Clone)]
+pub struct ParticleEmitter {
+    x: f32,
+    y: f32,
+    rate: f32,
+    pending: f32,
+    speed: (f32, f32),
+    direction: (f32, f32),
+    lifetime: (Duration, Duration),
+    gravity: (f32, f32),
+    glyphs: Vec<char>,
+    colors: Vec<Color>,
+    max_particles: usize,
+    particles: Vec<Particle>,
+    random: Random,
+}
+
+impl ParticleEmitter {
+    /// Creates a [`ParticleEmitter`] at the given `position` that emits `'*'` particles in every
+    /// direction, with no gravity and no continuous emission.
+    pub fn new(position: Vec2) -> ParticleEmitter {
+        let seed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+        ParticleEmitter {
+            x: position.x as f32,
+            y: position.y as f32,
+            rate: 0.0,
+            pending: 0.0,
+            speed: (1.0, 5.0),
+            direction: (0.0, 360.0),
+            lifetime: (Duration::from_millis(500), Duration::from_secs(1)),
+            gravity: (0.0, 0.0),
+            glyphs: vec!['*'],
+            colors: Vec::new(),
+            max_particles: 1000,
+            particles: Vec::new(),
+            random: Random(seed | 1),
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that emits `rate` particles per second while
+    /// it is updated.
+    pub fn rate(mut self, rate: f32) -> ParticleEmitter {
+        self.rate = rate.max(0.0);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose particles start with a speed between
+    /// `min` and `max` cells per second.
+    pub fn speed(mut self, min: f32, max: f32) -> ParticleEmitter {
+        self.speed = (min, max);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose particles start moving within `spread`
+    /// degrees around the `angle` direction. Angles are measured in degrees counterclockwise,
+    /// with `0.0` pointing right and `90.0` pointing up.
+    pub fn direction(mut self, angle: f32, spread: f32) -> ParticleEmitter {
+        self.direction = (angle - spread / 2.0, angle + spread / 2.0);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one whose particles live between `min` and `max`.
+    pub fn lifetime(mut self, min: Duration, max: Duration) -> ParticleEmitter {
+        self.lifetime = (min, max.max(min));
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that accelerates its particles by the given
+    /// `gravity`, in cells per second squared. A positive `y` pulls them down.
+    pub fn gravity(mut self, gravity_x: f32, gravity_y: f32) -> ParticleEmitter {
+        self.gravity = (gravity_x, gravity_y);
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that draws its particles with the given
+    /// `glyphs`, from the first one when they are born to the last one when they die.
+    pub fn glyphs(mut self, glyphs: &[char]) -> ParticleEmitter {
+        self.glyphs = glyphs.to_vec();
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that colors its particles through the given
+    /// `colors` along their lifetime. Without colors, particles take the color of the
+    /// [`Pencil`].
+    pub fn colors(mut self, colors: &[Color]) -> ParticleEmitter {
+        self.colors = colors.to_vec();
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that never has more than `max` live
+    /// particles. New particles are not emitted while the limit is reached.
+    pub fn max_particles(mut self, max: usize) -> ParticleEmitter {
+        self.max_particles = max;
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with a fixed random `seed`, so that it always
+    /// produces the same particles.
+    pub fn seed(mut self, seed: u64) -> ParticleEmitter {
+        self.random = Random(seed | 1);
+        self
+    }
+
+    pub fn position(&self) -> Vec2 {
+        Vec2::xy(self.x.round() as i32, self.y.round() as i32)
+    }
+
+    /// Moves the emitter. Particles already emitted are not moved.
+    pub fn set_position(&mut self, position: Vec2) -> &mut ParticleEmitter {
+        self.x = position.x as f32;
+        self.y = position.y as f32;
+        self
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Returns `true` if there are no live particles.
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Removes all the live particles.
+    pub fn clear(&mut self) -> &mut ParticleEmitter {
+        self.particles.clear();
+        self
+    }
+
+    /// Emits a burst of `count` particles at the position of the emitter.
+    pub fn emit(&mut self, count: usize) -> &mut ParticleEmitter {
+        let (x, y) = (self.x, self.y);
+        self.spawn(x, y, count);
+        self
+    }
+
+    /// Emits a burst of `count` particles at the given `position`, without moving the emitter.
+    pub fn emit_at(&mut self, position: Vec2, count: usize) -> &mut ParticleEmitter {
+        self.spawn(position.x as f32, position.y as f32, count);
+        self
+    }
+
+    fn spawn(&mut self, x: f32, y: f32, count: usize) {
+        let count = count.min(self.max_particles.saturating_sub(self.particles.len()));
+        for _ in 0..count {
+            let speed = self.random.range(self.speed.0, self.speed.1);
+            let angle = self.random.range(self.direction.0, self.direction.1) * PI / 180.0;
+            let (min, max) = self.lifetime;
+            let lifetime = min + (max - min).mul_f32(self.random.next());
+            self.particles.push(Particle {
+                x,
+                y,
+                velocity_x: speed * angle.cos(),
+                velocity_y: -speed * angle.sin(),
+                age: Duration::ZERO,
+                lifetime,
+            });
+        }
+    }
+
+    /// Advances the particles by the elapsed time `dt`, emitting new ones at the configured rate
+    /// and removing the ones whose lifetime is over.
+    pub fn update(&mut self, dt: Duration) -> &mut ParticleEmitter {
+        let seconds = dt.as_secs_f32();
+        for particle in &mut self.particles {
+            particle.velocity_x += self.gravity.0 * seconds;
+            particle.velocity_y += self.gravity.1 * seconds;
+            particle.x += particle.velocity_x * seconds;
+            particle.y += particle.velocity_y * seconds;
+            particle.age += dt;
+        }
+        self.particles
+            .retain(|particle| particle.age < particle.lifetime);
+
+        self.pending += self.rate * seconds;
+        let count = self.pending as usize;
+        self.pending -= count as f32;
+        self.emit(count)
+    }
+
+    /// Returns the glyph and the color, if any, of a particle at its current age.
+    fn appearance(&self, particle: &Particle) -> (char, Option<Color>) {
+        let progress = particle.progress();
+        let glyph = match self.glyphs.len() {
+            0 => '*',
+            len => self.glyphs[((progress * len as f32) as usize).min(len - 1)],
+        };
+        let color = match self.colors.len() {
+            0 => None,
+            1 => Some(self.colors[0]),
+            len => {
+                let position = progress * (len - 1) as f32;
+                let index = (position as usize).min(len - 2);
+                let t = position - index as f32;
+                Some(self.colors[index].lerp(&self.colors[index + 1], t))
+            }
+        };
+        (glyph, color)
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws the live particles of the given `emitter`, each one with the glyph and color that
+    /// correspond to its age.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_particles(&mut self, emitter: &ParticleEmitter) -> &mut Pencil<'a> {
+        let foreground = *self.foreground();
+        for particle in emitter.particles() {
+            let (glyph, color) = emitter.appearance(particle);
+            self.set_foreground(color.unwrap_or(foreground));
+            self.draw_char(glyph, particle.position());
+        }
+        self.set_foreground(foreground)
+    }
+}
@@ -1,7 +1,8 @@
 //! # Spatial
 //!
-//! The `spatial` module provides the [`Vec2`] struct to specify positions on the terminal screen
-//! and the [`Direction`] enum to specify and provide utility methods for relative directions.
+//! The `spatial` module provides the [`Vec2`] struct to specify positions on the terminal screen,
+//! the [`Rect`] struct to specify areas of it and the [`Direction`] enum to specify and provide
+//! utility methods for relative directions.
 
 use num::cast::ToPrimitive;
 use std::cmp::Ordering;
@@ -253,6 +254,74 @@ impl From<Direction> for Vec2 {
     }
 }
 
+/// Represents a rectangle on the terminal screen by the position of its top-left corner and its
+/// dimension.
+///
+/// ```rust
+/// # use ruscii::spatial::{Rect, Vec2};
+/// #
+/// let rect = Rect::new(Vec2::xy(2, 1), Vec2::xy(10, 4));
+/// assert!(rect.contains(Vec2::xy(11, 4)));
+/// assert!(!rect.contains(Vec2::xy(12, 4)));
+/// assert_eq!(rect.shrink(1), Rect::new(Vec2::xy(3, 2), Vec2::xy(8, 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub position: Vec2,
+    pub dimension: Vec2,
+}
+
+impl Rect {
+    /// Constructs a [`Rect`] with its top-left corner at `position` and the given `dimension`.
+    pub fn new(position: Vec2, dimension: Vec2) -> Rect {
+        Rect {
+            position,
+            dimension,
+        }
+    }
+
+    /// Constructs a [`Rect`] with the given `dimension` and its top-left corner at (0, 0).
+    pub fn from_dimension(dimension: Vec2) -> Rect {
+        Rect::new(Vec2::zero(), dimension)
+    }
+
+    /// Checks if the point represented by the given `pos` is inside the [`Rect`].
+    pub fn contains(&self, pos: Vec2) -> bool {
+        let end = self.position + self.dimension;
+        self.position.x <= pos.x && self.position.y <= pos.y && pos.x < end.x && pos.y < end.y
+    }
+
+    /// Returns the point at the center of the [`Rect`], rounded towards its top-left corner.
+    pub fn center(&self) -> Vec2 {
+        self.position + self.dimension / 2
+    }
+
+    /// Returns a [`Rect`] with the given `dimension` centered in this one, rounded towards its
+    /// top-left corner.
+    ///
+    /// ```rust
+    /// # use ruscii::spatial::{Rect, Vec2};
+    /// #
+    /// let screen = Rect::from_dimension(Vec2::xy(80, 24));
+    /// assert_eq!(screen.centered(Vec2::xy(20, 5)).position, Vec2::xy(30, 9));
+    /// ```
+    pub fn centered(&self, dimension: Vec2) -> Rect {
+        Rect::new(self.position + (self.dimension - dimension) / 2, dimension)
+    }
+
+    /// Returns a [`Rect`] with `margin` cells removed from each side. The dimension of the
+    /// result is never negative.
+    pub fn shrink(&self, margin: i32) -> Rect {
+        Rect::new(
+            self.position + Vec2::xy(margin, margin),
+            Vec2::xy(
+                (self.dimension.x - 2 * margin).max(0),
+                (self.dimension.y - 2 * margin).max(0),
+            ),
+        )
+    }
+}
+
 /// The relative directions in a two-dimensional coordinate system, including up, down, left,
 /// right, and none.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
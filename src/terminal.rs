@@ -34,8 +34,12 @@
 //! }
 //! ```
 
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
 use std::io::{self, BufWriter, Write};
+use std::str::FromStr;
 
+use super::drawing::{self, Rotation};
 use super::spatial::Vec2;
 use crossterm as ct;
 
@@ -62,6 +66,9 @@ use crossterm as ct;
 ///
 /// For reference, see the
 /// [256 Colors Cheat Sheet](https://www.ditig.com/256-colors-cheat-sheet).
+///
+/// A [`Color::Rgb`] can hold any color. The [`Window`] quantizes it, like the rest of colors, to
+/// its [`ColorDepth`] when drawing.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Color {
     Black,
@@ -76,10 +83,12 @@ pub enum Color {
     Yellow,
     Magenta,
     Xterm(u8),
+    Rgb(Rgb),
 }
 
 impl Color {
-    /// Converts this [`Color`] to its corresponding Xterm number.
+    /// Converts this [`Color`] to its corresponding Xterm number. A [`Color::Rgb`] is converted
+    /// to the number of the closest color of the Xterm palette.
     pub fn code(&self) -> u8 {
         match *self {
             Color::Black => 16,
@@ -94,10 +103,352 @@ impl Color {
             Color::Yellow => 226,
             Color::Magenta => 201,
             Color::Xterm(code) => code,
+            Color::Rgb(rgb) => nearest_xterm(rgb),
+        }
+    }
+
+    /// Returns the RGB components of this [`Color`] in the default Xterm palette.
+    ///
+    /// ```rust
+    /// # use ruscii::terminal::{Color, Rgb};
+    /// #
+    /// assert_eq!(Color::Red.rgb(), Rgb::new(255, 0, 0));
+    /// assert_eq!(Color::Xterm(244).rgb(), Rgb::new(128, 128, 128));
+    /// ```
+    pub fn rgb(&self) -> Rgb {
+        if let Color::Rgb(rgb) = *self {
+            return rgb;
+        }
+        const SYSTEM: [(u8, u8, u8); 16] = [
+            (0, 0, 0),
+            (128, 0, 0),
+            (0, 128, 0),
+            (128, 128, 0),
+            (0, 0, 128),
+            (128, 0, 128),
+            (0, 128, 128),
+            (192, 192, 192),
+            (128, 128, 128),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (0, 0, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+        match self.code() {
+            code @ 0..=15 => {
+                let (r, g, b) = SYSTEM[code as usize];
+                Rgb::new(r, g, b)
+            }
+            code @ 16..=231 => {
+                let index = code - 16;
+                Rgb::new(
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            code => {
+                let level = 8 + (code - 232) * 10;
+                Rgb::new(level, level, level)
+            }
+        }
+    }
+
+    /// Returns this [`Color`] with its RGB components moved towards black by the given `amount`,
+    /// from `0.0` (unchanged) to `1.0` (black).
+    ///
+    /// ```rust
+    /// # use ruscii::terminal::{Color, Rgb};
+    /// #
+    /// assert_eq!(Color::Red.darken(0.5), Color::Rgb(Rgb::new(128, 0, 0)));
+    /// assert_eq!(Color::Red.lighten(0.5), Color::Rgb(Rgb::new(255, 128, 128)));
+    /// assert_eq!(Color::Red.desaturate(1.0), Color::Rgb(Rgb::new(128, 128, 128)));
+    /// ```
+    pub fn darken(&self, amount: f32) -> Color {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |value: u8| (value as f32 * (1.0 - amount)).round() as u8;
+        let rgb = self.rgb();
+        Color::Rgb(Rgb::new(channel(rgb.r), channel(rgb.g), channel(rgb.b)))
+    }
+
+    /// Returns this [`Color`] with its RGB components moved towards white by the given `amount`,
+    /// from `0.0` (unchanged) to `1.0` (white).
+    pub fn lighten(&self, amount: f32) -> Color {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |value: u8| (value as f32 + (255 - value) as f32 * amount).round() as u8;
+        let rgb = self.rgb();
+        Color::Rgb(Rgb::new(channel(rgb.r), channel(rgb.g), channel(rgb.b)))
+    }
+
+    /// Returns this [`Color`] with its saturation reduced by the given `amount`, from `0.0`
+    /// (unchanged) to `1.0` (gray).
+    pub fn desaturate(&self, amount: f32) -> Color {
+        let mut hsl = Hsl::from(*self);
+        hsl.s *= 1.0 - amount.clamp(0.0, 1.0);
+        Color::from(hsl)
+    }
+
+    /// Returns the closest [`Color`] that a terminal with the given [`ColorDepth`] can show.
+    ///
+    /// ```rust
+    /// # use ruscii::terminal::{Color, ColorDepth, Rgb};
+    /// #
+    /// let teal = Color::Rgb(Rgb::new(0, 175, 135));
+    /// assert_eq!(teal.quantize(ColorDepth::TrueColor), teal);
+    /// assert_eq!(teal.quantize(ColorDepth::Xterm256), Color::Xterm(36));
+    /// assert_eq!(teal.quantize(ColorDepth::Ansi16), Color::Xterm(6));
+    /// ```
+    pub fn quantize(&self, depth: ColorDepth) -> Color {
+        match (depth, *self) {
+            (ColorDepth::TrueColor, color) => color,
+            (ColorDepth::Xterm256, Color::Rgb(rgb)) => Color::Xterm(nearest_xterm(rgb)),
+            (ColorDepth::Xterm256, color) => color,
+            (ColorDepth::Ansi16, color) if color.code() < 16 => Color::Xterm(color.code()),
+            (ColorDepth::Ansi16, color) => {
+                let rgb = color.rgb();
+                let code = (0..16)
+                    .min_by_key(|code| Color::Xterm(*code).rgb().distance(&rgb))
+                    .unwrap();
+                Color::Xterm(code)
+            }
+        }
+    }
+}
+
+/// The number of colors that a terminal can show.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ColorDepth {
+    /// The 16 system colors.
+    Ansi16,
+    /// The 256 colors of the Xterm palette.
+    Xterm256,
+    /// Any RGB color.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the [`ColorDepth`] of the terminal from the `COLORTERM` and `TERM` environment
+    /// variables. Terminals that do not advertise their depth are assumed to support
+    /// [`ColorDepth::Xterm256`].
+    pub fn detect() -> ColorDepth {
+        let variable = |name| std::env::var(name).unwrap_or_default().to_ascii_lowercase();
+        let (colorterm, term) = (variable("COLORTERM"), variable("TERM"));
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term == "linux" || term == "vt100" || term.ends_with("-16color") {
+            ColorDepth::Ansi16
+        } else {
+            ColorDepth::Xterm256
+        }
+    }
+}
+
+/// The intensity of each component in the 6×6×6 color cube of the Xterm palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// A color given by its red, green and blue components.
+///
+/// An [`Rgb`] is drawn on the terminal as a [`Color::Rgb`], and can be converted to and from
+/// [`Hsl`]:
+///
+/// ```rust
+/// # use ruscii::terminal::{Color, Hsl, Rgb};
+/// #
+/// assert_eq!(Color::from(Rgb::new(0, 175, 135)), Color::Rgb(Rgb::new(0, 175, 135)));
+/// assert_eq!(Hsl::from(Rgb::new(255, 0, 0)), Hsl::new(0.0, 1.0, 0.5));
+/// assert_eq!(Rgb::from(Hsl::new(120.0, 1.0, 0.25)), Rgb::new(0, 128, 0));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    fn distance(&self, other: &Rgb) -> u32 {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+        d(self.r, other.r) + d(self.g, other.g) + d(self.b, other.b)
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        color.rgb()
+    }
+}
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        Color::Rgb(rgb)
+    }
+}
+
+/// Returns the number of the closest color from the color cube or the grayscale ramp of the
+/// Xterm palette.
+fn nearest_xterm(rgb: Rgb) -> u8 {
+    let level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|i| (CUBE_LEVELS[*i] as i32 - value as i32).abs())
+            .unwrap() as u8
+    };
+    let (r, g, b) = (level(rgb.r), level(rgb.g), level(rgb.b));
+    let cube = 16 + r * 36 + g * 6 + b;
+
+    let average = (rgb.r as u32 + rgb.g as u32 + rgb.b as u32) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if Color::Xterm(gray).rgb().distance(&rgb) < Color::Xterm(cube).rgb().distance(&rgb) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// A color given by its hue, in degrees from `0.0` to `360.0`, and its saturation and lightness,
+/// from `0.0` to `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl Hsl {
+    pub fn new(h: f32, s: f32, l: f32) -> Hsl {
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = (
+            rgb.r as f32 / 255.0,
+            rgb.g as f32 / 255.0,
+            rgb.b as f32 / 255.0,
+        );
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return Hsl::new(0.0, 0.0, l);
+        }
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        Hsl::new(h, s, l)
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Self {
+        let (s, l) = (hsl.s.clamp(0.0, 1.0), hsl.l.clamp(0.0, 1.0));
+        let h = hsl.h.rem_euclid(360.0) / 60.0;
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let channel = |value: f32| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Rgb::new(channel(r), channel(g), channel(b))
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        Color::Rgb(Rgb::from(hsl))
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        Hsl::from(color.rgb())
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses a [`Color`] from its name in `snake_case` (e.g. `"dark_grey"`), from its Xterm
+    /// number (e.g. `"36"`) or from its hexadecimal RGB code (e.g. `"#00af87"`). Names are
+    /// case-insensitive.
+    ///
+    /// ```rust
+    /// # use ruscii::terminal::{Color, Rgb};
+    /// #
+    /// assert_eq!("Red".parse::<Color>().unwrap(), Color::Red);
+    /// assert_eq!("dark_grey".parse::<Color>().unwrap(), Color::DarkGrey);
+    /// assert_eq!("36".parse::<Color>().unwrap(), Color::Xterm(36));
+    /// assert_eq!("#00AF87".parse::<Color>().unwrap(), Color::Rgb(Rgb::new(0, 175, 135)));
+    /// assert!("reddish".parse::<Color>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+        let invalid = || ParseColorError {
+            value: s.to_string(),
+        };
+        if let Some(hex) = name.strip_prefix('#') {
+            let channel = |i: usize| {
+                hex.get(i..i + 2)
+                    .and_then(|c| u8::from_str_radix(c, 16).ok())
+            };
+            return match (hex.len(), channel(0), channel(2), channel(4)) {
+                (6, Some(r), Some(g), Some(b)) => Ok(Color::Rgb(Rgb::new(r, g, b))),
+                _ => Err(invalid()),
+            };
         }
+        match name.as_str() {
+            "black" => Ok(Color::Black),
+            "white" => Ok(Color::White),
+            "grey" | "gray" => Ok(Color::Grey),
+            "dark_grey" | "dark_gray" | "darkgrey" | "darkgray" => Ok(Color::DarkGrey),
+            "light_grey" | "light_gray" | "lightgrey" | "lightgray" => Ok(Color::LightGrey),
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            "cyan" => Ok(Color::Cyan),
+            "yellow" => Ok(Color::Yellow),
+            "magenta" => Ok(Color::Magenta),
+            _ => name.parse::<u8>().map(Color::Xterm).map_err(|_| invalid()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseColorError {
+    value: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a color name, an Xterm number or an RGB code",
+            self.value
+        )
     }
 }
 
+impl Error for ParseColorError {}
+
 /// The font weight.
 ///
 /// Represents the boldness of text on the terminal screen.
@@ -116,9 +467,20 @@ fn style_impl(style: Style) -> ct::style::Attribute {
 }
 */
 
+fn color_impl(color: Color) -> ct::style::Color {
+    match color {
+        Color::Rgb(rgb) => ct::style::Color::Rgb {
+            r: rgb.r,
+            g: rgb.g,
+            b: rgb.b,
+        },
+        color => ct::style::Color::AnsiValue(color.code()),
+    }
+}
+
 /// Represents all the data needed to display a character on the terminal screen with text [`Style`]
 /// and foreground and background [`Color`].
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VisualElement {
     pub style: Style,
     pub background: Color,
@@ -143,6 +505,7 @@ pub struct Canvas {
     data: Vec<VisualElement>,
     dimension: Vec2,
     default_element: VisualElement,
+    cursor: Option<Vec2>,
 }
 
 impl Canvas {
@@ -155,6 +518,7 @@ impl Canvas {
             data,
             dimension,
             default_element: *default_element,
+            cursor: None,
         }
     }
 
@@ -172,6 +536,18 @@ impl Canvas {
         self.dimension
     }
 
+    /// Returns the position where the terminal cursor is shown, if any.
+    pub fn cursor(&self) -> Option<Vec2> {
+        self.cursor
+    }
+
+    /// Sets the position where the [`Window`] shows the terminal cursor after drawing the
+    /// [`Canvas`], or hides it with [`None`]. The cursor is hidden again when the [`Window`] is
+    /// cleared, so it has to be set every frame.
+    pub fn set_cursor(&mut self, position: Option<Vec2>) {
+        self.cursor = position;
+    }
+
     /// Checks if the point represented by the given `pos` would be within the dimensions of the
     /// [`Canvas`].
     ///
@@ -224,6 +600,35 @@ impl Canvas {
         }
     }
 
+    /// Calls `f` on every cell of the rectangle with the given `dimension` whose top-left corner
+    /// is at the given `position`, to change the cells that are already drawn. Cells outside of
+    /// the [`Canvas`] are skipped.
+    ///
+    /// ```rust
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, Color, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(10, 5), &VisualElement::default());
+    /// canvas.transform(Vec2::xy(8, 3), Vec2::xy(4, 4), |element| element.background = Color::Red);
+    ///
+    /// assert_eq!(canvas.elem(Vec2::xy(9, 4)).unwrap().background, Color::Red);
+    /// assert_eq!(canvas.elem(Vec2::xy(7, 4)).unwrap().background, Color::Black);
+    /// ```
+    pub fn transform(
+        &mut self,
+        position: Vec2,
+        dimension: Vec2,
+        mut f: impl FnMut(&mut VisualElement),
+    ) {
+        for y in position.y..position.y + dimension.y {
+            for x in position.x..position.x + dimension.x {
+                if let Some(element) = self.elem_mut(Vec2::xy(x, y)) {
+                    f(element);
+                }
+            }
+        }
+    }
+
     /// Clears all of the [`VisualElement`] cells in the grid by setting them to clones of the
     /// default element.
     pub fn clear(&mut self) {
@@ -239,20 +644,131 @@ impl Canvas {
     pub fn data(&self) -> &Vec<VisualElement> {
         &self.data
     }
+
+    /// Returns a copy of the rectangle with the given `dimension` whose top-left corner is at the
+    /// given `position`. Cells outside of the [`Canvas`] are set to its default element.
+    pub fn region(&self, position: Vec2, dimension: Vec2) -> Canvas {
+        let mut region = Canvas::new(dimension, self.default_element());
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let pos = Vec2::xy(x, y);
+                if let (Some(source), Some(target)) =
+                    (self.elem(position + pos), region.elem_mut(pos))
+                {
+                    *target = *source;
+                }
+            }
+        }
+        region
+    }
+
+    /// Returns a copy of the [`Canvas`] rotated clockwise by the given [`Rotation`]. Characters
+    /// with a rotated counterpart, such as `─` and `│` or `▀` and `▐`, are replaced.
+    ///
+    /// ```rust
+    /// # use ruscii::drawing::{Pencil, Rotation};
+    /// # use ruscii::spatial::Vec2;
+    /// # use ruscii::terminal::{Canvas, VisualElement};
+    /// #
+    /// let mut canvas = Canvas::new(Vec2::xy(3, 1), &VisualElement::default());
+    /// Pencil::new(&mut canvas).draw_text("┌─>", Vec2::zero());
+    ///
+    /// let rotated = canvas.rotate(Rotation::Degrees90);
+    /// assert_eq!(rotated.dimension(), Vec2::xy(1, 3));
+    /// let column: String = (0..3).map(|y| rotated.elem(Vec2::y(y)).unwrap().value).collect();
+    /// assert_eq!(column, "┐│>");
+    /// ```
+    pub fn rotate(&self, rotation: Rotation) -> Canvas {
+        let dimension = rotation.rotate_dimension(self.dimension());
+        let mut rotated = Canvas::new(dimension, self.default_element());
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let pos = Vec2::xy(x, y);
+                let source = rotation.source(pos, self.dimension());
+                if let (Some(source), Some(target)) = (self.elem(source), rotated.elem_mut(pos)) {
+                    *target = *source;
+                    target.value = rotation.rotate_char(source.value);
+                }
+            }
+        }
+        rotated
+    }
+
+    /// Mirrors the [`Canvas`] left to right. Characters with a mirrored counterpart, such as
+    /// `/` and `\` or `┌` and `┐`, are swapped.
+    pub fn mirror_horizontal(&mut self) {
+        let dimension = self.dimension();
+        for y in 0..dimension.y {
+            for x in 0..dimension.x / 2 {
+                self.swap_elems(Vec2::xy(x, y), Vec2::xy(dimension.x - 1 - x, y));
+            }
+        }
+        self.map_values(drawing::mirror_horizontal);
+    }
+
+    /// Mirrors the [`Canvas`] top to bottom. Characters with a mirrored counterpart, such as
+    /// `▀` and `▄` or `┌` and `└`, are swapped.
+    pub fn mirror_vertical(&mut self) {
+        let dimension = self.dimension();
+        for y in 0..dimension.y / 2 {
+            for x in 0..dimension.x {
+                self.swap_elems(Vec2::xy(x, y), Vec2::xy(x, dimension.y - 1 - y));
+            }
+        }
+        self.map_values(drawing::mirror_vertical);
+    }
+
+    /// Returns a copy of the [`Canvas`] scaled by the given integer `factor` in each axis, where
+    /// each cell is repeated `factor.x` times horizontally and `factor.y` times vertically.
+    /// Factors smaller than 1 are taken as 1.
+    pub fn scale(&self, factor: Vec2) -> Canvas {
+        let factor = Vec2::xy(factor.x.max(1), factor.y.max(1));
+        let dimension = self.dimension() * factor;
+        let mut scaled = Canvas::new(dimension, self.default_element());
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let pos = Vec2::xy(x, y);
+                if let (Some(source), Some(target)) =
+                    (self.elem(pos / factor), scaled.elem_mut(pos))
+                {
+                    *target = *source;
+                }
+            }
+        }
+        scaled
+    }
+
+    fn swap_elems(&mut self, a: Vec2, b: Vec2) {
+        if let (Some(&first), Some(&second)) = (self.elem(a), self.elem(b)) {
+            *self.elem_mut(a).unwrap() = second;
+            *self.elem_mut(b).unwrap() = first;
+        }
+    }
+
+    fn map_values(&mut self, f: fn(char) -> char) {
+        let dimension = self.dimension();
+        self.transform(Vec2::zero(), dimension, |element| {
+            element.value = f(element.value)
+        });
+    }
 }
 
 /// An object that exposes a [`Canvas`] and can write the data within it to the standard output.
 pub struct Window {
     canvas: Canvas,
+    color_depth: ColorDepth,
+    mouse_capture: bool,
     target: BufWriter<io::Stdout>,
 }
 
 impl Default for Window {
-    /// Constructs a [`Window`] with the automatically detected size and the target set to the
-    /// [`io::stdout`].
+    /// Constructs a [`Window`] with the automatically detected size and [`ColorDepth`] and the
+    /// target set to the [`io::stdout`].
     fn default() -> Self {
         Self {
             canvas: Canvas::new(size(), &VisualElement::default()),
+            color_depth: ColorDepth::detect(),
+            mouse_capture: false,
             target: BufWriter::with_capacity(
                 size().x as usize * size().y as usize * 50,
                 io::stdout(),
@@ -274,6 +790,23 @@ impl Window {
         self.canvas.dimension()
     }
 
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Sets the [`ColorDepth`] to which the colors of the [`Canvas`] are quantized when drawing.
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
+    }
+
+    /// Enables or disables the capture of the mouse, needed to read the mouse wheel with
+    /// [`Keyboard::last_wheel_delta`](crate::keyboard::Keyboard::last_wheel_delta). It takes
+    /// effect when the [`Window`] is opened. While the mouse is captured, the terminal does not
+    /// select text with it.
+    pub fn set_mouse_capture(&mut self, enable: bool) {
+        self.mouse_capture = enable;
+    }
+
     pub fn open(&mut self) {
         ct::queue!(self.target, ct::terminal::EnterAlternateScreen).unwrap();
         ct::queue!(self.target, ct::style::ResetColor).unwrap();
@@ -283,6 +816,9 @@ impl Window {
         )
         .unwrap();
         ct::queue!(self.target, ct::cursor::Hide).unwrap();
+        if self.mouse_capture {
+            ct::queue!(self.target, ct::event::EnableMouseCapture).unwrap();
+        }
 
         self.clean_state();
         self.raw_mode(true);
@@ -301,6 +837,9 @@ impl Window {
     pub fn close(&mut self) {
         self.raw_mode(false);
 
+        if self.mouse_capture {
+            ct::queue!(self.target, ct::event::DisableMouseCapture).unwrap();
+        }
         ct::queue!(self.target, ct::cursor::Show).unwrap();
         ct::queue!(
             self.target,
@@ -318,13 +857,15 @@ impl Window {
             self.canvas = Canvas::new(size(), self.canvas.default_element());
         } else {
             self.canvas.fill(&self.canvas.default_element().clone());
+            self.canvas.set_cursor(None);
         }
     }
 
     pub fn draw(&mut self) {
         self.clean_state();
-        let mut last_foreground = self.canvas.default_element().foreground;
-        let mut last_background = self.canvas.default_element().background;
+        let depth = self.color_depth;
+        let mut last_foreground = self.canvas.default_element().foreground.quantize(depth);
+        let mut last_background = self.canvas.default_element().background.quantize(depth);
         //let mut last_style = self.canvas.default_element().style;
         let target = &mut self.target;
 
@@ -336,31 +877,39 @@ impl Window {
                 last_style = element.style
             }
             */
-            if last_foreground != element.foreground {
-                let term_color = ct::style::Color::AnsiValue(element.foreground.code());
+            let foreground = element.foreground.quantize(depth);
+            if last_foreground != foreground {
+                let term_color = color_impl(foreground);
                 ct::queue!(target, ct::style::SetForegroundColor(term_color)).unwrap();
-                last_foreground = element.foreground
+                last_foreground = foreground
             }
-            if last_background != element.background {
-                let term_color = ct::style::Color::AnsiValue(element.background.code());
+            let background = element.background.quantize(depth);
+            if last_background != background {
+                let term_color = color_impl(background);
                 ct::queue!(target, ct::style::SetBackgroundColor(term_color)).unwrap();
-                last_background = element.background
+                last_background = background
             }
             ct::queue!(target, ct::style::Print(element.value)).unwrap();
         }
         self.clean_state();
+        match self.canvas.cursor() {
+            Some(cursor) if self.canvas.contains(cursor) => {
+                let (x, y) = (cursor.x as u16, cursor.y as u16);
+                ct::queue!(self.target, ct::cursor::MoveTo(x, y), ct::cursor::Show).unwrap();
+            }
+            _ => ct::queue!(self.target, ct::cursor::Hide).unwrap(),
+        }
         self.target.flush().unwrap();
     }
 
     fn clean_state(&mut self) {
         //ct::queue!(self.target, ct::style::SetAttribute(ct::style::Attribute::NoBold)).unwrap();
 
-        let term_foreground =
-            ct::style::Color::AnsiValue(self.canvas.default_element().foreground.code());
+        let depth = self.color_depth;
+        let term_foreground = color_impl(self.canvas.default_element().foreground.quantize(depth));
         ct::queue!(self.target, ct::style::SetForegroundColor(term_foreground)).unwrap();
 
-        let term_background =
-            ct::style::Color::AnsiValue(self.canvas.default_element().background.code());
+        let term_background = color_impl(self.canvas.default_element().background.quantize(depth));
         ct::queue!(self.target, ct::style::SetBackgroundColor(term_background)).unwrap();
 
         ct::queue!(self.target, ct::cursor::MoveTo(0, 0)).unwrap();
@@ -0,0 +1,231 @@
+//! # Tile Map
+//!
+//! The `tilemap` module provides a [`TileMap`], a grid of tiles that can be larger than the
+//! terminal, and a [`Camera`] that decides which part of it is visible.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use ruscii::drawing::Pencil;
+//! # use ruscii::spatial::Vec2;
+//! # use ruscii::terminal::{Canvas, Color, VisualElement};
+//! # use ruscii::tilemap::{Camera, TileMap};
+//! #
+//! let mut map = TileMap::from_text("#####\n#...#\n#...#\n#####", |c| match c {
+//!     '#' => 1,
+//!     _ => 0,
+//! });
+//! map.set_tile_kind(0, VisualElement { value: '.', ..Default::default() });
+//! map.set_tile_kind(1, VisualElement { value: '#', foreground: Color::Grey, ..Default::default() });
+//!
+//! let mut camera = Camera::new(Vec2::xy(3, 3)).bounds(map.dimension());
+//! camera.follow(Vec2::xy(4, 3));
+//! assert_eq!(camera.position(), Vec2::xy(2, 1));
+//! assert_eq!(camera.world_to_screen(Vec2::xy(4, 3)), Vec2::xy(2, 2));
+//!
+//! let mut canvas = Canvas::new(Vec2::xy(3, 3), &VisualElement::default());
+//! Pencil::new(&mut canvas).draw_tilemap(&map, &camera, Vec2::zero());
+//! assert_eq!(canvas.elem(Vec2::xy(2, 2)).unwrap().value, '#');
+//! ```
+
+use super::drawing::Pencil;
+use super::spatial::Vec2;
+use super::terminal::VisualElement;
+
+use std::collections::HashMap;
+
+/// A grid of tile ids, each one drawn with the [`VisualElement`] registered for its kind.
+#[derive(Debug, Clone)]
+pub struct TileMap {
+    dimension: Vec2,
+    tiles: Vec<usize>,
+    kinds: HashMap<usize, VisualElement>,
+}
+
+impl TileMap {
+    /// Creates a [`TileMap`] with the given `dimension` and every tile set to `fill`.
+    pub fn new(dimension: Vec2, fill: usize) -> TileMap {
+        TileMap {
+            dimension,
+            tiles: vec![fill; (dimension.x.max(0) * dimension.y.max(0)) as usize],
+            kinds: HashMap::new(),
+        }
+    }
+
+    /// Creates a [`TileMap`] from the lines of `text`, converting each character into a tile id
+    /// with `tile_id`. Its width is the one of the longest line and the missing tiles of shorter
+    /// lines take the id of a space.
+    pub fn from_text(text: &str, tile_id: impl Fn(char) -> usize) -> TileMap {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut map = TileMap::new(Vec2::xy(width, lines.len()), tile_id(' '));
+        for (y, line) in lines.iter().enumerate() {
+            for (x, value) in line.chars().enumerate() {
+                map.set_tile(Vec2::xy(x, y), tile_id(value));
+            }
+        }
+        map
+    }
+
+    pub fn dimension(&self) -> Vec2 {
+        self.dimension
+    }
+
+    /// Checks if the given `pos` is a tile of the map.
+    pub fn contains(&self, pos: Vec2) -> bool {
+        0 <= pos.x && 0 <= pos.y && pos.x < self.dimension.x && pos.y < self.dimension.y
+    }
+
+    /// Returns the id of the tile at the given `pos`, or [`None`] if it is outside of the map.
+    pub fn tile(&self, pos: Vec2) -> Option<usize> {
+        if self.contains(pos) {
+            Some(self.tiles[(pos.y * self.dimension.x + pos.x) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the id of the tile at the given `pos`. Positions outside of the map are ignored.
+    pub fn set_tile(&mut self, pos: Vec2, id: usize) -> &mut TileMap {
+        if self.contains(pos) {
+            self.tiles[(pos.y * self.dimension.x + pos.x) as usize] = id;
+        }
+        self
+    }
+
+    /// Sets the [`VisualElement`] used to draw the tiles with the given `id`. Tiles whose id has
+    /// no element are not drawn.
+    pub fn set_tile_kind(&mut self, id: usize, element: VisualElement) -> &mut TileMap {
+        self.kinds.insert(id, element);
+        self
+    }
+
+    pub fn tile_kind(&self, id: usize) -> Option<&VisualElement> {
+        self.kinds.get(&id)
+    }
+}
+
+/// The part of a [`TileMap`], or of any other world, that is shown on the screen.
+///
+/// The camera has a `viewport`, the size of the screen region where the world is drawn, and a
+/// `position`, the world point shown at the top-left corner of that region. If it has bounds, it
+/// never shows anything outside of them, and a world smaller than the viewport is centered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Camera {
+    position: Vec2,
+    viewport: Vec2,
+    bounds: Option<Vec2>,
+}
+
+impl Camera {
+    /// Creates a [`Camera`] at the world origin, with the given `viewport` and no bounds.
+    pub fn new(viewport: Vec2) -> Camera {
+        Camera {
+            position: Vec2::zero(),
+            viewport,
+            bounds: None,
+        }
+    }
+
+    /// Consumes the receiver and returns a new one that stays within a world of the given
+    /// `dimension`, usually [`TileMap::dimension`].
+    pub fn bounds(mut self, dimension: Vec2) -> Camera {
+        self.bounds = Some(dimension);
+        self.set_position(self.position);
+        self
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    pub fn viewport(&self) -> Vec2 {
+        self.viewport
+    }
+
+    /// Moves the top-left corner of the camera to the given world `position`, clamped to the
+    /// bounds.
+    pub fn set_position(&mut self, position: Vec2) -> &mut Camera {
+        self.position = match self.bounds {
+            Some(bounds) => Vec2::xy(
+                clamp_axis(position.x, self.viewport.x, bounds.x),
+                clamp_axis(position.y, self.viewport.y, bounds.y),
+            ),
+            None => position,
+        };
+        self
+    }
+
+    /// Changes the size of the viewport, for example when the terminal is resized, keeping the
+    /// camera within its bounds.
+    pub fn set_viewport(&mut self, viewport: Vec2) -> &mut Camera {
+        self.viewport = viewport;
+        self.set_position(self.position)
+    }
+
+    /// Centers the camera on the given world `target`, as far as the bounds allow.
+    pub fn follow(&mut self, target: Vec2) -> &mut Camera {
+        self.set_position(target - self.viewport / 2)
+    }
+
+    /// Converts a world position into a position relative to the top-left corner of the
+    /// viewport.
+    pub fn world_to_screen(&self, world: Vec2) -> Vec2 {
+        world - self.position
+    }
+
+    /// Converts a position relative to the top-left corner of the viewport into a world
+    /// position.
+    pub fn screen_to_world(&self, screen: Vec2) -> Vec2 {
+        screen + self.position
+    }
+
+    /// Checks if the given world position is inside the viewport.
+    pub fn is_visible(&self, world: Vec2) -> bool {
+        let screen = self.world_to_screen(world);
+        0 <= screen.x && 0 <= screen.y && screen.x < self.viewport.x && screen.y < self.viewport.y
+    }
+}
+
+fn clamp_axis(position: i32, viewport: i32, bound: i32) -> i32 {
+    if bound <= viewport {
+        (bound - viewport) / 2
+    } else {
+        position.clamp(0, bound - viewport)
+    }
+}
+
+impl<'a> Pencil<'a> {
+    /// Draws the part of the `map` seen by the `camera` into the screen region whose top-left
+    /// corner is at the given `position` and whose size is the viewport of the camera.
+    ///
+    /// Only the visible tiles are drawn, with the [`VisualElement`] of their kind.
+    ///
+    /// Returns the receiver for chaining.
+    pub fn draw_tilemap(
+        &mut self,
+        map: &TileMap,
+        camera: &Camera,
+        position: Vec2,
+    ) -> &mut Pencil<'a> {
+        let (foreground, background, style) =
+            (*self.foreground(), *self.background(), *self.style());
+        for y in 0..camera.viewport().y {
+            for x in 0..camera.viewport().x {
+                let screen = Vec2::xy(x, y);
+                let element = map
+                    .tile(camera.screen_to_world(screen))
+                    .and_then(|id| map.tile_kind(id));
+                if let Some(element) = element {
+                    self.set_foreground(element.foreground)
+                        .set_background(element.background)
+                        .set_style(element.style)
+                        .draw_char(element.value, position + screen);
+                }
+            }
+        }
+        self.set_foreground(foreground)
+            .set_background(background)
+            .set_style(style)
+    }
+}
@@ -0,0 +1,341 @@
+//! # Tween
+//!
+//! The `tween` module provides the [`Tween`] struct to animate values, such as positions, numbers
+//! or colors, over time following an [`Easing`] curve.
+//!
+//! ## Example
+//!
+//! A [`Tween`] is made of one or more steps, each one moving the value to a target in a given
+//! time. It is advanced with the elapsed time of each frame, usually [`State::dt`]:
+//!
+//! ```rust,no_run
+//! # use std::time::Duration;
+//! # use ruscii::app::{App, State};
+//! # use ruscii::drawing::Pencil;
+//! # use ruscii::spatial::Vec2;
+//! # use ruscii::terminal::Window;
+//! # use ruscii::tween::{Easing, Tween};
+//! #
+//! let mut app = App::default();
+//! let mut title = Tween::new(Vec2::xy(10, -5))
+//!     .to(Vec2::xy(10, 5), Duration::from_millis(800), Easing::BounceOut)
+//!     .wait(Duration::from_secs(1))
+//!     .to(Vec2::xy(-20, 5), Duration::from_millis(500), Easing::CubicIn);
+//!
+//! app.run(|app_state: &mut State, window: &mut Window| {
+//!     title.update(*app_state.dt());
+//!     Pencil::new(window.canvas_mut()).draw_text("Ruscii", title.value());
+//! });
+//! ```
+//!
+//! [`State::dt`]: crate::app::State::dt
+
+use super::spatial::Vec2;
+use super::terminal::{Color, Hsl, Rgb};
+
+use std::f32::consts::PI;
+use std::time::Duration;
+
+/// A curve that maps the linear progress of a step, from `0.0` to `1.0`, to the progress of the
+/// animated value.
+///
+/// `In` curves start slowly, `Out` curves end slowly and `InOut` curves do both. Elastic and bounce
+/// curves go past their target before settling on it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Easing {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t`, which is clamped to `[0.0, 1.0]`.
+    ///
+    /// Every curve starts at `0.0` and ends at `1.0`.
+    ///
+    /// ```rust
+    /// # use ruscii::tween::Easing;
+    /// #
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::BounceOut.apply(1.0), 1.0);
+    /// ```
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t).powi(2),
+            Easing::QuadInOut if t < 0.5 => 2.0 * t * t,
+            Easing::QuadInOut => 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0,
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut if t < 0.5 => 4.0 * t.powi(3),
+            Easing::CubicInOut => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+            _ if t == 0.0 || t == 1.0 => t,
+            Easing::ElasticIn => {
+                -(2f32.powf(10.0 * t - 10.0)) * ((10.0 * t - 10.75) * (2.0 * PI / 3.0)).sin()
+            }
+            Easing::ElasticOut => {
+                2f32.powf(-10.0 * t) * ((10.0 * t - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+            }
+            Easing::ElasticInOut => {
+                let wave = ((20.0 * t - 11.125) * (2.0 * PI / 4.5)).sin();
+                if t < 0.5 {
+                    -(2f32.powf(20.0 * t - 10.0) * wave) / 2.0
+                } else {
+                    2f32.powf(-20.0 * t + 10.0) * wave / 2.0 + 1.0
+                }
+            }
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut if t < 0.5 => (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0,
+            Easing::BounceInOut => (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0,
+        }
+    }
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// Values that can be linearly interpolated.
+///
+/// Integer values are rounded to the closest integer, and colors are interpolated by their RGB
+/// components into a [`Color::Rgb`]. [`Hsl`] colors go around the hue circle the short way.
+pub trait Lerp: Clone {
+    /// Returns the value at `t` between `self` (at `0.0`) and `other` (at `1.0`). Values of `t`
+    /// outside of `[0.0, 1.0]` extrapolate.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t as f64
+    }
+}
+
+macro_rules! impl_lerp_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Lerp for $t {
+                fn lerp(&self, other: &Self, t: f32) -> Self {
+                    (*self as f64).lerp(&(*other as f64), t).round() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Lerp for Vec2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec2::xy(self.x.lerp(&other.x, t), self.y.lerp(&other.y, t))
+    }
+}
+
+impl Lerp for Rgb {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let channel = |a: u8, b: u8| (a as f32).lerp(&(b as f32), t).round().clamp(0.0, 255.0);
+        Rgb::new(
+            channel(self.r, other.r) as u8,
+            channel(self.g, other.g) as u8,
+            channel(self.b, other.b) as u8,
+        )
+    }
+}
+
+impl Lerp for Hsl {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let turn = (other.h - self.h + 180.0).rem_euclid(360.0) - 180.0;
+        Hsl::new(
+            (self.h + turn * t).rem_euclid(360.0),
+            self.s.lerp(&other.s, t),
+            self.l.lerp(&other.l, t),
+        )
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        if t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        Color::from(self.rgb().lerp(&other.rgb(), t))
+    }
+}
+
+/// A step of a [`Tween`]: moving to a target, or waiting if there is no target.
+#[derive(Debug, Clone)]
+struct Step<T> {
+    target: Option<T>,
+    duration: Duration,
+    easing: Easing,
+}
+
+/// An animation of a value through a sequence of steps.
+///
+/// Each step moves the value from where the previous one left it to a new target, following an
+/// [`Easing`] curve, or keeps it still for a while. Steps are chained with [`Tween::to`] and
+/// [`Tween::wait`], and the whole sequence can be repeated with [`Tween::repeat`].
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use ruscii::tween::{Easing, Tween};
+/// #
+/// let second = Duration::from_secs(1);
+/// let mut tween = Tween::new(0.0)
+///     .to(10.0, second, Easing::Linear)
+///     .to(0.0, second, Easing::Linear);
+///
+/// tween.update(second / 2);
+/// assert_eq!(tween.value(), 5.0);
+/// tween.update(second);
+/// assert_eq!(tween.value(), 5.0);
+/// tween.update(second);
+/// assert_eq!(tween.value(), 0.0);
+/// assert!(tween.is_finished());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tween<T: Lerp> {
+    start: T,
+    steps: Vec<Step<T>>,
+    repeat: bool,
+    elapsed: Duration,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Creates a [`Tween`] that starts at the given value and has no steps.
+    pub fn new(start: T) -> Tween<T> {
+        Tween {
+            start,
+            steps: Vec::new(),
+            repeat: false,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Creates a [`Tween`] with a single step from `from` to `to`.
+    pub fn between(from: T, to: T, duration: Duration, easing: Easing) -> Tween<T> {
+        Tween::new(from).to(to, duration, easing)
+    }
+
+    /// Consumes the receiver and returns a new one with a step that moves the value to `target`
+    /// during the given `duration`.
+    pub fn to(mut self, target: T, duration: Duration, easing: Easing) -> Tween<T> {
+        self.steps.push(Step {
+            target: Some(target),
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Consumes the receiver and returns a new one with a step that keeps the value still during
+    /// the given `duration`.
+    pub fn wait(mut self, duration: Duration) -> Tween<T> {
+        self.steps.push(Step {
+            target: None,
+            duration,
+            easing: Easing::Linear,
+        });
+        self
+    }
+
+    /// Consumes the receiver and returns a new one that starts over from the first step after
+    /// the last one, or not.
+    pub fn repeat(mut self, repeat: bool) -> Tween<T> {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns the time that all the steps take.
+    pub fn duration(&self) -> Duration {
+        self.steps.iter().map(|step| step.duration).sum()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Advances the tween by the elapsed time `dt`.
+    pub fn update(&mut self, dt: Duration) -> &mut Tween<T> {
+        self.elapsed += dt;
+        let duration = self.duration();
+        if self.elapsed >= duration {
+            self.elapsed = if self.repeat && !duration.is_zero() {
+                Duration::from_nanos((self.elapsed.as_nanos() % duration.as_nanos()) as u64)
+            } else {
+                duration
+            };
+        }
+        self
+    }
+
+    /// Moves the tween back to its first step.
+    pub fn reset(&mut self) -> &mut Tween<T> {
+        self.elapsed = Duration::ZERO;
+        self
+    }
+
+    /// Returns `true` once the last step has ended. Repeating tweens never finish.
+    pub fn is_finished(&self) -> bool {
+        !self.repeat && self.elapsed >= self.duration()
+    }
+
+    /// Returns the value at the current time.
+    pub fn value(&self) -> T {
+        let mut value = self.start.clone();
+        let mut time = self.elapsed;
+        for step in &self.steps {
+            let target = match &step.target {
+                Some(target) => target,
+                None if time < step.duration => return value,
+                None => {
+                    time -= step.duration;
+                    continue;
+                }
+            };
+            if time < step.duration {
+                let t = time.as_secs_f32() / step.duration.as_secs_f32();
+                return value.lerp(target, step.easing.apply(t));
+            }
+            time -= step.duration;
+            value = target.clone();
+        }
+        value
+    }
+}